@@ -0,0 +1,352 @@
+//! Binary data: owned byte buffers, numeric vectors backed by JS typed arrays and views borrowing V8 memory.
+
+use crate::errors::{Error, Result};
+use deno_core::v8::{self, HandleScope, Local, Value};
+use std::{
+    cell::Cell,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// Bytes converted from a `Uint8Array`, any other typed array, a `DataView`, an `ArrayBuffer` or a
+/// `SharedArrayBuffer`, and into a `Uint8Array`; a `Vec<u8>` converts to an Array of Numbers instead.
+/// Converting into a JS value hands the `Vec` to V8 as the backing store, without copying it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteBuf(pub Vec<u8>);
+
+/// Defines a vector of numbers converted from and into the typed array `$array`
+macro_rules! typed_vec {
+    ($($(#[$doc:meta])* $name:ident($t:ty) => $array:ident if $is:ident),*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Debug, Default, PartialEq)]
+            pub struct $name(pub Vec<$t>);
+
+            impl $name {
+                /// The name of the JS typed array, used in errors
+                pub(crate) const JS_TYPE: &'static str = stringify!($array);
+
+                pub(crate) fn is(input: &Local<'_, Value>) -> bool {
+                    input.$is()
+                }
+
+                pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+                    let elements = bytes
+                        .chunks_exact(size_of::<$t>())
+                        .map(|chunk| <$t>::from_ne_bytes(chunk.try_into().expect("chunks have the element size")))
+                        .collect();
+                    Self(elements)
+                }
+
+                pub(crate) fn into_typed_array<'a>(self, scope: &mut HandleScope<'a>) -> Local<'a, Value> {
+                    let length = self.0.len();
+                    let bytes: Vec<u8> = self.0.into_iter().flat_map(<$t>::to_ne_bytes).collect();
+                    let buffer = array_buffer(scope, bytes);
+                    v8::$array::new(scope, buffer, 0, length)
+                        .map_or_else(|| v8::undefined(scope).into(), Into::into)
+                }
+            }
+        )*
+
+        impl_buffer_traits!(ByteBuf(u8) $(, $name($t))*);
+    };
+}
+
+/// Lets the wrappers be used like the `Vec` they hold
+macro_rules! impl_buffer_traits {
+    ($($name:ident($t:ty)),*) => {
+        $(
+            impl Deref for $name {
+                type Target = Vec<$t>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl DerefMut for $name {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+
+            impl From<Vec<$t>> for $name {
+                fn from(elements: Vec<$t>) -> Self {
+                    Self(elements)
+                }
+            }
+
+            impl From<$name> for Vec<$t> {
+                fn from(wrapper: $name) -> Self {
+                    wrapper.0
+                }
+            }
+        )*
+    };
+}
+
+typed_vec! {
+    /// Numbers converted from and into a `Float32Array`
+    Float32Vec(f32) => Float32Array if is_float32_array,
+    /// Numbers converted from and into a `Float64Array`
+    Float64Vec(f64) => Float64Array if is_float64_array,
+    /// Numbers converted from and into an `Int32Array`
+    Int32Vec(i32) => Int32Array if is_int32_array
+}
+
+/// Copies the bytes of an `ArrayBufferView` (a typed array or `DataView`), `ArrayBuffer` or `SharedArrayBuffer`
+///
+/// # Errors
+/// `ExpectedBuffer` when the value holds no binary data.
+pub(crate) fn copy_bytes(input: Local<'_, Value>) -> Result<Vec<u8>> {
+    if let Ok(view) = input.try_cast::<v8::ArrayBufferView>() {
+        let mut bytes = vec![0; view.byte_length()];
+        let copied = view.copy_contents(&mut bytes);
+        bytes.truncate(copied);
+        return Ok(bytes);
+    }
+
+    let store = if let Ok(buffer) = input.try_cast::<v8::ArrayBuffer>() {
+        buffer.get_backing_store()
+    } else if let Ok(buffer) = input.try_cast::<v8::SharedArrayBuffer>() {
+        buffer.get_backing_store()
+    } else {
+        return Err(Error::ExpectedBuffer("ArrayBuffer or ArrayBufferView"));
+    };
+
+    Ok(store.iter().map(Cell::get).collect())
+}
+
+/// A new `ArrayBuffer` backed by `bytes`; the `Vec` is moved into V8 without copying
+pub(crate) fn array_buffer<'a>(scope: &mut HandleScope<'a>, bytes: Vec<u8>) -> Local<'a, v8::ArrayBuffer> {
+    let store = v8::ArrayBuffer::new_backing_store_from_vec(bytes).make_shared();
+    v8::ArrayBuffer::with_backing_store(scope, &store)
+}
+
+/// A new `Uint8Array` backed by `bytes`, without copying
+pub(crate) fn uint8_array<'a>(scope: &mut HandleScope<'a>, bytes: Vec<u8>) -> Local<'a, Value> {
+    let length = bytes.len();
+    let buffer = array_buffer(scope, bytes);
+    v8::Uint8Array::new(scope, buffer, 0, length).map_or_else(|| v8::undefined(scope).into(), Into::into)
+}
+
+/// A view of the bytes of an `ArrayBuffer`, typed array or `DataView`, read without copying.
+///
+/// The view is tied to the scope it was converted in and shares ownership of the V8 backing store, so the memory stays
+/// valid while it is borrowed. As with `v8::ArrayBufferView::get_contents`, the bytes are read in place: the view can
+/// not write to them, and JS sees no change. Memory of a `SharedArrayBuffer` can change on other threads at any time
+/// and is not viewed.
+#[derive(Clone)]
+pub struct BufferView<'s> {
+    store: v8::SharedRef<v8::BackingStore>,
+    offset: usize,
+    length: usize,
+    scope: PhantomData<&'s ()>,
+}
+
+impl<'s> BufferView<'s> {
+    /// Views `input`, which must hold binary data that is not shared
+    ///
+    /// # Errors
+    /// `ExpectedBuffer` when the value holds no binary data, or binary data of a `SharedArrayBuffer`.
+    pub fn new(input: Local<'s, Value>, scope: &mut HandleScope<'s>) -> Result<Self> {
+        Self::view(input, scope)
+    }
+
+    pub(crate) fn view(input: Local<'_, Value>, scope: &mut HandleScope<'_>) -> Result<Self> {
+        let (store, offset, length) = if let Ok(view) = input.try_cast::<v8::ArrayBufferView>() {
+            let buffer = view
+                .buffer(scope)
+                .ok_or(Error::ExpectedBuffer("ArrayBuffer or ArrayBufferView"))?;
+            (buffer.get_backing_store(), view.byte_offset(), view.byte_length())
+        } else if let Ok(buffer) = input.try_cast::<v8::ArrayBuffer>() {
+            (buffer.get_backing_store(), 0, buffer.byte_length())
+        } else {
+            return Err(Error::ExpectedBuffer("ArrayBuffer or ArrayBufferView"));
+        };
+
+        // typed arrays over a SharedArrayBuffer end up here as well
+        if store.is_shared() {
+            return Err(Error::ExpectedBuffer(
+                "ArrayBuffer or ArrayBufferView that is not shared",
+            ));
+        }
+
+        Ok(Self {
+            store,
+            offset,
+            length,
+            scope: PhantomData,
+        })
+    }
+
+    /// The number of viewed bytes
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Whether no bytes are viewed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The viewed bytes; empty when the buffer has been shrunk or detached since
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        let cells = self
+            .store
+            .get(self.offset..self.offset + self.length)
+            .unwrap_or_default();
+        // SAFETY: `Cell<u8>` has the same layout as `u8`, and the view never writes through the cells
+        unsafe { &*(std::ptr::from_ref::<[Cell<u8>]>(cells) as *const [u8]) }
+    }
+
+    /// The viewed bytes as `f32`s; `None` when they are not aligned or not a whole number of elements
+    #[must_use]
+    pub fn as_f32s(&self) -> Option<&[f32]> {
+        // SAFETY: every bit pattern is a valid `f32`
+        aligned(unsafe { self.as_bytes().align_to() })
+    }
+
+    /// The viewed bytes as `f64`s; `None` when they are not aligned or not a whole number of elements
+    #[must_use]
+    pub fn as_f64s(&self) -> Option<&[f64]> {
+        // SAFETY: every bit pattern is a valid `f64`
+        aligned(unsafe { self.as_bytes().align_to() })
+    }
+
+    /// The viewed bytes as `i32`s; `None` when they are not aligned or not a whole number of elements
+    #[must_use]
+    pub fn as_i32s(&self) -> Option<&[i32]> {
+        // SAFETY: every bit pattern is a valid `i32`
+        aligned(unsafe { self.as_bytes().align_to() })
+    }
+
+    /// The viewed bytes as `u32`s; `None` when they are not aligned or not a whole number of elements
+    #[must_use]
+    pub fn as_u32s(&self) -> Option<&[u32]> {
+        // SAFETY: every bit pattern is a valid `u32`
+        aligned(unsafe { self.as_bytes().align_to() })
+    }
+
+    /// The backing store and the range of it that is viewed, to hand the same memory back to V8
+    pub(crate) fn parts(&self) -> (&v8::SharedRef<v8::BackingStore>, usize, usize) {
+        (&self.store, self.offset, self.length)
+    }
+}
+
+fn aligned<'a, T>((prefix, elements, suffix): (&'a [u8], &'a [T], &'a [u8])) -> Option<&'a [T]> {
+    (prefix.is_empty() && suffix.is_empty()).then_some(elements)
+}
+
+impl fmt::Debug for BufferView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferView")
+            .field("offset", &self.offset)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BufferView, ByteBuf, Float32Vec, Float64Vec, Int32Vec};
+    use crate::{errors::Error, into::IntoValue, setup, ConversionMode, TryFromValue};
+    use deno_core::v8;
+
+    #[test]
+    fn can_round_trip_binary_data() {
+        setup::with_scope(|scope| {
+            let value = ByteBuf(vec![1, 2, 255]).into_value(scope);
+            assert!(value.is_uint8_array());
+            assert_eq!(
+                ByteBuf::try_from_value(&value, scope).unwrap(),
+                ByteBuf(vec![1, 2, 255])
+            );
+
+            // the whole buffer behind a typed array, or a DataView over it
+            let buffer = value.try_cast::<v8::ArrayBufferView>().unwrap().buffer(scope).unwrap();
+            let bytes = ByteBuf::try_from_value(&buffer.into(), scope).unwrap();
+            assert_eq!(bytes, ByteBuf(vec![1, 2, 255]));
+            let view = v8::DataView::new(scope, buffer, 1, 2);
+            assert_eq!(
+                ByteBuf::try_from_value(&view.into(), scope).unwrap(),
+                ByteBuf(vec![2, 255])
+            );
+
+            let value = Float32Vec(vec![0.5, -1.25]).into_value(scope);
+            assert!(value.is_float32_array());
+            assert_eq!(
+                Float32Vec::try_from_value(&value, scope).unwrap(),
+                Float32Vec(vec![0.5, -1.25])
+            );
+            let value = Float64Vec(vec![f64::MAX]).into_value(scope);
+            assert!(value.is_float64_array());
+            assert_eq!(
+                Float64Vec::try_from_value(&value, scope).unwrap(),
+                Float64Vec(vec![f64::MAX])
+            );
+            let value = Int32Vec(vec![i32::MIN, 7]).into_value(scope);
+            assert!(value.is_int32_array());
+            assert_eq!(
+                Int32Vec::try_from_value(&value, scope).unwrap(),
+                Int32Vec(vec![i32::MIN, 7])
+            );
+
+            // plain Arrays are only accepted when converting leniently
+            let value = vec![1_u8, 2].into_value(scope);
+            assert!(value.is_array());
+            assert_eq!(ByteBuf::try_from_value(&value, scope).unwrap(), ByteBuf(vec![1, 2]));
+            ByteBuf::try_from_value_with(&value, scope, ConversionMode::Strict).expect_err("an Array is no buffer");
+            Int32Vec::try_from_value_with(&value, scope, ConversionMode::Strict).expect_err("an Array is no buffer");
+        });
+    }
+
+    #[test]
+    fn can_view_buffers_without_copying() {
+        setup::with_scope(|scope| {
+            let value = ByteBuf(vec![1, 2, 3, 4]).into_value(scope);
+            let view = BufferView::try_from_value(&value, scope).unwrap();
+            assert_eq!(view.as_bytes(), [1, 2, 3, 4]);
+
+            // the view shares its memory with the JS value
+            let array = value.try_cast::<v8::Uint8Array>().unwrap();
+            let nine = v8::Integer::new(scope, 9).into();
+            array.set_index(scope, 0, nine);
+            assert_eq!(view.as_bytes(), [9, 2, 3, 4]);
+            let shared = view.clone().into_value(scope);
+            assert!(shared.is_uint8_array());
+            let eight = v8::Integer::new(scope, 8).into();
+            array.set_index(scope, 1, eight);
+            assert_eq!(
+                ByteBuf::try_from_value(&shared, scope).unwrap(),
+                ByteBuf(vec![9, 8, 3, 4])
+            );
+
+            let value = Float64Vec(vec![1.5, 2.5]).into_value(scope);
+            let view = BufferView::new(value, scope).unwrap();
+            assert_eq!(view.len(), 16);
+            assert_eq!(view.as_f64s(), Some(&[1.5, 2.5][..]));
+            assert_eq!(view.as_i32s().map(<[i32]>::len), Some(4));
+
+            let value = vec![1_u8].into_value(scope);
+            BufferView::try_from_value(&value, scope).expect_err("an Array is no buffer");
+        });
+    }
+
+    #[test]
+    fn cannot_view_shared_buffers() {
+        setup::with_scope(|scope| {
+            let buffer = v8::SharedArrayBuffer::new(scope, 4).unwrap();
+            let error = BufferView::try_from_value(&buffer.into(), scope).unwrap_err();
+            assert!(matches!(error, Error::ExpectedBuffer(_)));
+
+            // copies are fine
+            let bytes = ByteBuf::try_from_value(&buffer.into(), scope).unwrap();
+            assert_eq!(bytes, ByteBuf(vec![0; 4]));
+        });
+    }
+}
@@ -0,0 +1,597 @@
+//! Dates and times: converted from and into a JS `Date` by default, or into an ISO 8601 string or the
+//! milliseconds since the Unix epoch with `#[v8(with = "v8_derive::date::iso")]` or
+//! `#[v8(with = "v8_derive::date::millis")]`.
+//!
+//! Whatever the representation, a Date, an ISO 8601 string or a Number of milliseconds is accepted when converting
+//! from a JS value leniently; converting strictly accepts the selected representation only. A `Date` holds whole milliseconds, so finer precision is lost on the way. Types without an
+//! offset are taken to be in UTC.
+
+use crate::{
+    errors::{Error, Result},
+    from::ConversionMode,
+};
+use deno_core::v8;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A date or time that can be converted from and into a JS `Date`
+pub trait DateTimeValue: Sized {
+    /// The milliseconds since the Unix epoch
+    fn to_millis(&self) -> f64;
+
+    /// The value at `millis` milliseconds since the Unix epoch, or `None` when it can not be represented
+    fn from_millis(millis: f64) -> Option<Self>;
+
+    /// The value as an ISO 8601 string
+    fn to_iso(&self) -> String;
+
+    /// Parses an ISO 8601 string, or returns `None` when it is not valid
+    fn from_iso(text: &str) -> Option<Self>;
+}
+
+/// Converts a Date, an ISO 8601 string or a Number of milliseconds since the Unix epoch; only a Date when
+/// converting strictly
+///
+/// # Errors
+/// `ExpectedDate` for values of any other type, `InvalidDate` when the value can not be represented.
+pub fn try_from_value_with<'a, T: DateTimeValue>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<T> {
+    if mode.is_strict() && !input.is_date() {
+        return Err(Error::ExpectedDate);
+    }
+    convert(*input, scope)
+}
+
+/// Converts any of the representations
+fn convert<T: DateTimeValue>(input: v8::Local<'_, v8::Value>, scope: &mut v8::HandleScope<'_>) -> Result<T> {
+    if input.is_date() {
+        let date: v8::Local<v8::Date> = input.try_cast()?;
+        let millis = date.value_of();
+        return T::from_millis(millis).ok_or_else(|| Error::InvalidDate(millis.to_string()));
+    }
+
+    if input.is_string() {
+        let text = input.to_rust_string_lossy(scope);
+        return T::from_iso(&text).ok_or(Error::InvalidDate(text));
+    }
+
+    if input.is_number() {
+        let millis = input.number_value(scope).ok_or(Error::ExpectedDate)?;
+        return T::from_millis(millis).ok_or_else(|| Error::InvalidDate(millis.to_string()));
+    }
+
+    Err(Error::ExpectedDate)
+}
+
+/// Converts the value into a JS `Date`
+#[allow(clippy::needless_pass_by_value)] // `with` modules take the field by value
+pub fn into_value<'a, T: DateTimeValue>(value: T, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+    v8::Date::new(scope, value.to_millis()).map_or_else(|| v8::undefined(scope).into(), Into::into)
+}
+
+/// Dates and times converted into ISO 8601 strings
+pub mod iso {
+    use super::DateTimeValue;
+    use crate::{
+        errors::{Error, Result},
+        from::ConversionMode,
+    };
+    use deno_core::v8;
+
+    /// Converts a Date, an ISO 8601 string or a Number of milliseconds since the Unix epoch; only an ISO 8601
+    /// string when converting strictly
+    ///
+    /// # Errors
+    /// `ExpectedDate` for values of any other type, or `ExpectedString` when converting strictly, `InvalidDate`
+    /// when the value can not be represented.
+    pub fn try_from_value_with<'a, T: DateTimeValue>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<T> {
+        if mode.is_strict() && !input.is_string() {
+            return Err(Error::ExpectedString);
+        }
+        super::convert(*input, scope)
+    }
+
+    /// Converts the value into an ISO 8601 string
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn into_value<'a, T: DateTimeValue>(value: T, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        v8::String::new(scope, &value.to_iso())
+            .unwrap_or(v8::String::empty(scope))
+            .into()
+    }
+}
+
+/// Dates and times converted into the Number of milliseconds since the Unix epoch
+pub mod millis {
+    use super::DateTimeValue;
+    use crate::{
+        errors::{Error, Result},
+        from::ConversionMode,
+    };
+    use deno_core::v8;
+
+    /// Converts a Date, an ISO 8601 string or a Number of milliseconds since the Unix epoch; only a Number when
+    /// converting strictly
+    ///
+    /// # Errors
+    /// `ExpectedDate` for values of any other type, or `ExpectedF64` when converting strictly, `InvalidDate` when
+    /// the value can not be represented.
+    pub fn try_from_value_with<'a, T: DateTimeValue>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<T> {
+        if mode.is_strict() && !input.is_number() {
+            return Err(Error::ExpectedF64);
+        }
+        super::convert(*input, scope)
+    }
+
+    /// Converts the value into the Number of milliseconds since the Unix epoch
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn into_value<'a, T: DateTimeValue>(value: T, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        v8::Number::new(scope, value.to_millis()).into()
+    }
+}
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Whole milliseconds, or `None` for `NaN` and the infinities, which an invalid `Date` holds
+#[allow(clippy::cast_possible_truncation)]
+fn whole_millis(millis: f64) -> Option<i64> {
+    millis.is_finite().then(|| millis.floor() as i64)
+}
+
+impl DateTimeValue for SystemTime {
+    #[allow(clippy::cast_precision_loss)]
+    fn to_millis(&self) -> f64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_millis() as f64,
+            Err(before) => -(before.duration().as_millis() as f64),
+        }
+    }
+
+    fn from_millis(millis: f64) -> Option<Self> {
+        let millis = whole_millis(millis)?;
+        let offset = Duration::from_millis(millis.unsigned_abs());
+        if millis < 0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        }
+    }
+
+    /// Like `Date.prototype.toISOString`, e.g. `2024-02-29T09:30:00.000Z`
+    fn to_iso(&self) -> String {
+        whole_millis(self.to_millis()).map(format_iso).unwrap_or_default()
+    }
+
+    /// Accepts `2024-02-29`, `2024-02-29T09:30:00Z`, `2024-02-29T11:30:00.5+02:00` and `2024-02-29T09:30:00` in UTC
+    fn from_iso(text: &str) -> Option<Self> {
+        #[allow(clippy::cast_precision_loss)]
+        parse_iso(text).and_then(|millis| Self::from_millis(millis as f64))
+    }
+}
+
+/// Formats milliseconds since the Unix epoch as an ISO 8601 string in UTC
+fn format_iso(millis: i64) -> String {
+    let days = millis.div_euclid(MILLIS_PER_DAY);
+    let millis = millis.rem_euclid(MILLIS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let (hour, minute) = (millis / 3_600_000, millis / 60_000 % 60);
+    let (second, milli) = (millis / 1000 % 60, millis % 1000);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{milli:03}Z")
+}
+
+/// Parses an ISO 8601 date, or date and time with an optional offset, into milliseconds since the Unix epoch
+fn parse_iso(text: &str) -> Option<i64> {
+    fn number(digits: &str, len: usize) -> Option<i64> {
+        (digits.len() == len && digits.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| digits.parse().ok())
+            .flatten()
+    }
+
+    let (date, time) = text.split_once(['T', 't', ' ']).unwrap_or((text, "00:00:00Z"));
+    let mut date = date.split('-');
+    let (year, month, day) = (
+        number(date.next()?, 4)?,
+        number(date.next()?, 2)?,
+        number(date.next()?, 2)?,
+    );
+    let days = days_from_civil(year, month, day);
+    if date.next().is_some() || civil_from_days(days) != (year, month, day) {
+        return None;
+    }
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else if let Some(at) = time.rfind(['+', '-']) {
+        let (time, offset) = time.split_at(at);
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let minutes = number(hours, 2)? * 60 + number(minutes, 2)?;
+        (time, if offset.starts_with('-') { -minutes } else { minutes } * 60_000)
+    } else {
+        // without an offset the time is in UTC
+        (time, 0)
+    };
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut time = time.split(':');
+    let (hour, minute, second) = (
+        number(time.next()?, 2)?,
+        number(time.next()?, 2)?,
+        number(time.next()?, 2)?,
+    );
+    if time.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let milli = number(&format!("{fraction:0<3}")[..3], 3)?;
+
+    Some(days * MILLIS_PER_DAY + ((hour * 60 + minute) * 60 + second) * 1000 + milli - offset)
+}
+
+/// The days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let (era, year_of_era) = (year.div_euclid(400), year.rem_euclid(400));
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The year, month and day of the date `days` after 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let (era, day_of_era) = (days.div_euclid(146_097), days.rem_euclid(146_097));
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{whole_millis, DateTimeValue, MILLIS_PER_DAY};
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Timelike, Utc};
+
+    #[allow(clippy::cast_precision_loss)]
+    fn millis_of(date_time: &DateTime<Utc>) -> f64 {
+        date_time.timestamp_millis() as f64
+    }
+
+    fn utc_from_millis(millis: f64) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(whole_millis(millis)?)
+    }
+
+    impl DateTimeValue for DateTime<Utc> {
+        fn to_millis(&self) -> f64 {
+            millis_of(self)
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis)
+        }
+
+        fn to_iso(&self) -> String {
+            self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            DateTime::parse_from_rfc3339(text)
+                .ok()
+                .map(|date_time| date_time.to_utc())
+        }
+    }
+
+    /// Converted from a `Date` or Number with a zero offset, as those do not carry one
+    impl DateTimeValue for DateTime<FixedOffset> {
+        fn to_millis(&self) -> f64 {
+            millis_of(&self.to_utc())
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis).map(|date_time| date_time.fixed_offset())
+        }
+
+        fn to_iso(&self) -> String {
+            self.to_rfc3339_opts(SecondsFormat::AutoSi, false)
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            DateTime::parse_from_rfc3339(text).ok()
+        }
+    }
+
+    impl DateTimeValue for NaiveDateTime {
+        fn to_millis(&self) -> f64 {
+            millis_of(&self.and_utc())
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis).map(|date_time| date_time.naive_utc())
+        }
+
+        fn to_iso(&self) -> String {
+            self.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            text.parse().ok().or_else(|| {
+                DateTime::parse_from_rfc3339(text)
+                    .ok()
+                    .map(|date_time| date_time.naive_utc())
+            })
+        }
+    }
+
+    /// Midnight UTC of the date
+    impl DateTimeValue for NaiveDate {
+        fn to_millis(&self) -> f64 {
+            millis_of(&self.and_time(NaiveTime::MIN).and_utc())
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis).map(|date_time| date_time.date_naive())
+        }
+
+        fn to_iso(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            text.parse().ok().or_else(|| {
+                DateTime::parse_from_rfc3339(text)
+                    .ok()
+                    .map(|date_time| date_time.naive_utc().date())
+            })
+        }
+    }
+
+    /// The time on January 1, 1970 UTC; only the time of day is kept when converting from a JS value
+    impl DateTimeValue for NaiveTime {
+        #[allow(clippy::cast_precision_loss)]
+        fn to_millis(&self) -> f64 {
+            let millis = i64::from(self.num_seconds_from_midnight()) * 1000 + i64::from(self.nanosecond() / 1_000_000);
+            millis as f64
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            let millis = whole_millis(millis)?.rem_euclid(MILLIS_PER_DAY);
+            let nanos = u32::try_from(millis % 1000).ok()? * 1_000_000;
+            NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(millis / 1000).ok()?, nanos)
+        }
+
+        fn to_iso(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            text.parse().ok().or_else(|| {
+                DateTime::parse_from_rfc3339(text)
+                    .ok()
+                    .map(|date_time| date_time.naive_utc().time())
+            })
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{whole_millis, DateTimeValue};
+    use time::{format_description::well_known::Rfc3339, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    #[allow(clippy::cast_precision_loss)]
+    fn millis_of(date_time: OffsetDateTime) -> f64 {
+        (date_time.unix_timestamp_nanos() / 1_000_000) as f64
+    }
+
+    fn utc_from_millis(millis: f64) -> Option<OffsetDateTime> {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(whole_millis(millis)?) * 1_000_000).ok()
+    }
+
+    /// Parses an RFC 3339 date and time, taking one without an offset to be in UTC
+    fn utc_from_iso(text: &str) -> Option<OffsetDateTime> {
+        OffsetDateTime::parse(text, &Rfc3339)
+            .or_else(|_| OffsetDateTime::parse(&format!("{text}Z"), &Rfc3339))
+            .ok()
+            .map(|date_time| date_time.to_offset(UtcOffset::UTC))
+    }
+
+    /// Converted from a `Date` or Number in UTC, as those do not carry an offset
+    impl DateTimeValue for OffsetDateTime {
+        fn to_millis(&self) -> f64 {
+            millis_of(*self)
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis)
+        }
+
+        fn to_iso(&self) -> String {
+            self.format(&Rfc3339).unwrap_or_default()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            OffsetDateTime::parse(text, &Rfc3339).ok()
+        }
+    }
+
+    impl DateTimeValue for PrimitiveDateTime {
+        fn to_millis(&self) -> f64 {
+            millis_of(self.assume_utc())
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis).map(|date_time| PrimitiveDateTime::new(date_time.date(), date_time.time()))
+        }
+
+        fn to_iso(&self) -> String {
+            let iso = self.assume_utc().format(&Rfc3339).unwrap_or_default();
+            iso.trim_end_matches('Z').to_string()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            utc_from_iso(text).map(|date_time| PrimitiveDateTime::new(date_time.date(), date_time.time()))
+        }
+    }
+
+    /// Midnight UTC of the date
+    impl DateTimeValue for Date {
+        fn to_millis(&self) -> f64 {
+            millis_of(self.with_time(Time::MIDNIGHT).assume_utc())
+        }
+
+        fn from_millis(millis: f64) -> Option<Self> {
+            utc_from_millis(millis).map(OffsetDateTime::date)
+        }
+
+        fn to_iso(&self) -> String {
+            self.to_string()
+        }
+
+        fn from_iso(text: &str) -> Option<Self> {
+            utc_from_iso(text)
+                .or_else(|| utc_from_iso(&format!("{text}T00:00:00")))
+                .map(OffsetDateTime::date)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    use crate::{errors::Error, ConversionMode};
+    use crate::{into::IntoValue, setup, TryFromValue};
+    use deno_core::v8;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    #[cfg(feature = "chrono")]
+    use v8_derive_macros::{FromValue, IntoValue};
+
+    #[cfg(feature = "chrono")]
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Meeting {
+        starts: chrono::DateTime<chrono::Utc>,
+        #[v8(with = "crate::date::iso")]
+        day: chrono::NaiveDate,
+        #[v8(with = "crate::date::millis")]
+        updated: chrono::NaiveDateTime,
+    }
+
+    #[test]
+    fn can_round_trip_system_time() {
+        setup::with_scope(|scope| {
+            let queued = UNIX_EPOCH + Duration::from_millis(1_709_199_000_500);
+            let value = queued.into_value(scope);
+            assert!(value.is_date());
+            assert_eq!(SystemTime::try_from_value(&value, scope).unwrap(), queued);
+
+            let value = v8::String::new(scope, "2024-02-29T11:30:00.5+02:00").unwrap().into();
+            assert_eq!(SystemTime::try_from_value(&value, scope).unwrap(), queued);
+            // like the types without an offset, a time without one is in UTC
+            let value = v8::String::new(scope, "2024-02-29T09:30:00.5").unwrap().into();
+            assert_eq!(SystemTime::try_from_value(&value, scope).unwrap(), queued);
+            let value = UNIX_EPOCH.into_value(scope);
+            let value = super::iso::into_value(SystemTime::try_from_value(&value, scope).unwrap(), scope);
+            assert_eq!(value.to_rust_string_lossy(scope), "1970-01-01T00:00:00.000Z");
+        });
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn can_round_trip_dates() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        setup::with_scope(|scope| {
+            let starts = Utc.with_ymd_and_hms(2024, 2, 29, 9, 30, 0).unwrap();
+            let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+            let updated = day.and_hms_milli_opt(8, 15, 0, 250).unwrap();
+            let meeting = Meeting { starts, day, updated };
+            let value = meeting.into_value(scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "starts").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_date());
+            let key = v8::String::new(scope, "day").unwrap();
+            let js_day = object.get(scope, key.into()).unwrap();
+            assert_eq!(js_day.to_rust_string_lossy(scope), "2024-02-29");
+            let key = v8::String::new(scope, "updated").unwrap();
+            let js_updated = object.get(scope, key.into()).unwrap();
+            assert_eq!(js_updated.number_value(scope), Some(1_709_194_500_250.0));
+            assert_eq!(
+                Meeting::try_from_value(&value, scope).unwrap(),
+                Meeting { starts, day, updated }
+            );
+
+            // any representation is accepted
+            let value = v8::String::new(scope, "2024-02-29T09:30:00Z").unwrap().into();
+            assert_eq!(chrono::DateTime::<Utc>::try_from_value(&value, scope).unwrap(), starts);
+            let value = v8::Number::new(scope, 1_709_199_000_000.0).into();
+            assert_eq!(chrono::DateTime::<Utc>::try_from_value(&value, scope).unwrap(), starts);
+            let value = v8::String::new(scope, "yesterday").unwrap().into();
+            let error = NaiveDate::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::InvalidDate(text) if text == "yesterday"));
+            let value = v8::Boolean::new(scope, true).into();
+            let error = NaiveDate::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::ExpectedDate));
+
+            // but only the selected one when converting strictly
+            let value = v8::Number::new(scope, 1_709_199_000_000.0).into();
+            let error =
+                chrono::DateTime::<Utc>::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedDate));
+            let error =
+                super::iso::try_from_value_with::<NaiveDate>(&value, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedString));
+            let updated = super::millis::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap();
+            assert_eq!(starts.naive_utc(), updated);
+            let value = v8::String::new(scope, "2024-02-29").unwrap().into();
+            let error =
+                super::millis::try_from_value_with::<NaiveDate>(&value, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedF64));
+            let date = chrono::DateTime::<Utc>::into_value(starts, scope);
+            let error = super::iso::try_from_value_with::<NaiveDate>(&date, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedString));
+            assert_eq!(
+                chrono::DateTime::<Utc>::try_from_value_with(&date, scope, ConversionMode::Strict).unwrap(),
+                starts
+            );
+        });
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn can_round_trip_time_dates() {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+        setup::with_scope(|scope| {
+            let day = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+            let local = PrimitiveDateTime::new(day, Time::from_hms(9, 30, 0).unwrap());
+            let value = local.into_value(scope);
+            assert!(value.is_date());
+            assert_eq!(PrimitiveDateTime::try_from_value(&value, scope).unwrap(), local);
+            assert_eq!(
+                OffsetDateTime::try_from_value(&value, scope).unwrap(),
+                local.assume_utc()
+            );
+
+            let value = super::iso::into_value(day, scope);
+            assert_eq!(value.to_rust_string_lossy(scope), "2024-02-29");
+            assert_eq!(Date::try_from_value(&value, scope).unwrap(), day);
+            let value = v8::String::new(scope, "2024-02-29T09:30:00").unwrap().into();
+            assert_eq!(PrimitiveDateTime::try_from_value(&value, scope).unwrap(), local);
+        });
+    }
+}
@@ -0,0 +1,156 @@
+//! Durations: converted from and into a Number of milliseconds by default, or into a `{ secs, nanos }` object
+//! with `#[v8(with = "v8_derive::duration::secs_nanos")]`.
+//!
+//! Whatever the representation, both a Number of milliseconds and a `{ secs, nanos }` object are accepted when
+//! converting from a JS value.
+
+use crate::{
+    errors::{Error, Result},
+    from::{ConversionMode, TryFromValue},
+    helpers::{get_field_as, get_field_or_else},
+    into::IntoValue,
+};
+use deno_core::v8;
+use std::time::Duration;
+
+/// Converts a Number of milliseconds or a `{ secs, nanos }` object; `nanos` may be left out
+///
+/// # Errors
+/// `ExpectedDuration` for values of any other type, `OutOfRange` for negative durations and durations too long to
+/// represent.
+pub fn try_from_value_with<'a>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<Duration> {
+    if input.is_object() {
+        let secs = get_field_as("secs", input, scope, |value, scope| {
+            u64::try_from_value_with(value, scope, mode)
+        })?;
+        let nanos = get_field_or_else(
+            "nanos",
+            input,
+            scope,
+            |value, scope| u32::try_from_value_with(value, scope, mode),
+            || 0,
+        )?;
+        return Duration::from_secs(secs)
+            .checked_add(Duration::from_nanos(u64::from(nanos)))
+            .ok_or_else(|| Error::OutOfRange(format!("{{ secs: {secs}, nanos: {nanos} }}")));
+    }
+
+    if mode.is_strict() && !input.is_number() {
+        return Err(Error::ExpectedDuration);
+    }
+
+    // values that coerce to NaN, like `undefined` or "soon", are no duration at all
+    let millis = input
+        .number_value(scope)
+        .filter(|millis| !millis.is_nan())
+        .ok_or(Error::ExpectedDuration)?;
+    Duration::try_from_secs_f64(millis / 1000.0).map_err(|_| Error::OutOfRange(millis.to_string()))
+}
+
+/// Converts the duration into a Number of milliseconds, with the sub-millisecond part as fraction
+pub fn into_value<'a>(value: Duration, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+    v8::Number::new(scope, value.as_secs_f64() * 1000.0).into()
+}
+
+/// Durations converted into `{ secs, nanos }` objects, which keep every nanosecond
+pub mod secs_nanos {
+    use crate::{errors::Result, from::ConversionMode};
+    use deno_core::v8;
+    use std::time::Duration;
+
+    /// Converts a Number of milliseconds or a `{ secs, nanos }` object
+    ///
+    /// # Errors
+    /// `ExpectedDuration` for values of any other type, `OutOfRange` for negative durations and durations too long
+    /// to represent.
+    pub fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Duration> {
+        super::try_from_value_with(input, scope, mode)
+    }
+
+    /// Converts the duration into a `{ secs, nanos }` object
+    pub fn into_value<'a>(value: Duration, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        let object = v8::Object::new(scope);
+        super::set(scope, object, "secs", value.as_secs());
+        super::set(scope, object, "nanos", value.subsec_nanos());
+        object.into()
+    }
+}
+
+fn set(scope: &mut v8::HandleScope<'_>, object: v8::Local<'_, v8::Object>, name: &str, value: impl IntoValue) {
+    let (Some(key), value) = (v8::String::new(scope, name), value.into_value(scope)) else {
+        return;
+    };
+    object.set(scope, key.into(), value);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::Error, into::IntoValue, setup, ConversionMode, TryFromValue};
+    use deno_core::v8;
+    use std::time::Duration;
+    use v8_derive_macros::{FromValue, IntoValue};
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Timing {
+        timeout: Duration,
+        #[v8(with = "crate::duration::secs_nanos")]
+        elapsed: Duration,
+    }
+
+    #[test]
+    fn can_round_trip_durations() {
+        setup::with_scope(|scope| {
+            let timing = Timing {
+                timeout: Duration::from_millis(1500),
+                elapsed: Duration::new(3, 141_592_653),
+            };
+            let value = timing.into_value(scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "timeout").unwrap();
+            let timeout = object.get(scope, key.into()).unwrap();
+            assert_eq!(timeout.number_value(scope), Some(1500.0));
+            assert_eq!(
+                Timing::try_from_value(&value, scope).unwrap(),
+                Timing {
+                    timeout: Duration::from_millis(1500),
+                    elapsed: Duration::new(3, 141_592_653),
+                }
+            );
+
+            let value = v8::Number::new(scope, -1.0).into();
+            let error = Duration::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::OutOfRange(_)));
+            let value = v8::String::new(scope, "1500").unwrap().into();
+            assert_eq!(
+                Duration::try_from_value(&value, scope).unwrap(),
+                Duration::from_millis(1500)
+            );
+            let error = Duration::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedDuration));
+            let value = v8::undefined(scope).into();
+            let error = Duration::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::ExpectedDuration));
+            let value = v8::String::new(scope, "soon").unwrap().into();
+            let error = Duration::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::ExpectedDuration));
+
+            // { secs: u64::MAX, nanos: 1_000_000_000 }
+            let value = super::secs_nanos::into_value(Duration::MAX, scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "nanos").unwrap();
+            let nanos = v8::Number::new(scope, 1_000_000_000.0);
+            object.set(scope, key.into(), nanos.into());
+
+            let error = Duration::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::OutOfRange(_)));
+        });
+    }
+}
@@ -1,6 +1,44 @@
 use deno_core::v8;
+use std::fmt;
 use thiserror::Error as ThisError;
 
+/// A single step from a value into one of its parts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A property of an object, such as a struct field
+    Field(String),
+    /// An element of an array
+    Index(u32),
+    /// An entry of a map
+    Key(String),
+}
+
+/// The location of a value within the converted input, displayed like `[3].nested.age`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Path(Vec<PathSegment>);
+
+impl Path {
+    /// The segments from the outermost value inwards
+    #[must_use]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) => write!(f, "[{key:?}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(ThisError, Debug)]
 pub enum Error {
     #[error("Conversion failed; Expected Boolean")]
@@ -9,6 +47,8 @@ pub enum Error {
     ExpectedArray,
     #[error("Conversion failed; Expected Object")]
     ExpectedObject,
+    #[error("Conversion failed; Expected null or undefined")]
+    ExpectedNull,
     #[error("Conversion failed {source}")]
     ConversionFailed {
         #[from]
@@ -20,6 +60,8 @@ pub enum Error {
     InvalidField(String),
     #[error("Conversion failed; Expected String")]
     ExpectedString,
+    #[error("Conversion failed; Expected a single character")]
+    ExpectedChar,
     #[error("Conversion failed; Expected Int32")]
     ExpectedI32,
     #[error("Conversion failed; Expected Uint32")]
@@ -28,14 +70,128 @@ pub enum Error {
     ExpectedI64,
     #[error("Conversion failed; Expected Number")]
     ExpectedF64,
-    #[error("Conversion failed; Value out of range")]
-    OutOfRange,
+    #[error("Conversion failed; Value {0} out of range")]
+    OutOfRange(String),
+    #[error("Conversion failed; Expected Number or BigInt")]
+    ExpectedInteger,
+    #[error("Conversion failed; Expected {0}")]
+    ExpectedBuffer(&'static str),
+    #[error("Conversion failed; Expected an Array of {expected} elements, found {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
     #[error("Conversion failed; Expected Map")]
     ExpectedMap,
+    #[error("Conversion failed; Expected Set")]
+    ExpectedSet,
+    #[error("Conversion failed; Expected Date, String or Number")]
+    ExpectedDate,
+    #[error("Conversion failed; Invalid date {0}")]
+    InvalidDate(String),
+    #[error("Conversion failed; Expected Number or {{ secs, nanos }}")]
+    ExpectedDuration,
+    #[error("Conversion failed; Invalid UUID {0}")]
+    InvalidUuid(String),
+    #[error("Conversion failed; Invalid URL {0}")]
+    InvalidUrl(String),
+    #[error("Conversion failed; Invalid decimal {0}")]
+    InvalidDecimal(String),
+    #[error("Conversion failed; Invalid IP address {0}")]
+    InvalidIpAddr(String),
+    #[error("Conversion failed; Invalid socket address {0}")]
+    InvalidSocketAddr(String),
     #[error("Conversion failed; Failed to get property names")]
     FailedToGetPropertyNames,
     #[error("Conversion failed; Unsupported value type")]
     UnsupportedValueType,
+    #[error("Conversion failed; Value contains itself")]
+    CyclicValue,
+    /// An error raised by a `Serialize` or `Deserialize` implementation
+    #[cfg(feature = "serde")]
+    #[error("Conversion failed; {0}")]
+    Custom(String),
+    #[error("Conversion failed; Expected enum variant")]
+    ExpectedVariant,
+    #[error("Unknown variant: {0}")]
+    UnknownVariant(String),
+    #[error("Enum tag {0} not found")]
+    TagNotFound(String),
+    #[error("Content of variant {0} not found")]
+    ContentNotFound(String),
+    #[error("Conversion failed; Content of internally tagged variant {0} is not an Object")]
+    ExpectedTaggableContent(String),
+    #[error("Data did not match any variant of untagged enum {0}")]
+    NoMatchingVariant(String),
+    #[error("Conversion failed at {path}; Expected {expected}, got {actual}: {source}")]
+    AtPath {
+        /// Where in the input the conversion failed
+        path: Path,
+        /// The Rust type that was being converted to
+        expected: &'static str,
+        /// The `typeof` of the offending JS value
+        actual: String,
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// The location of the failure within the input, if known
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::AtPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can be placed at a location within the input: a single `Error`, or the list of errors gathered by
+/// `TryFromValue::try_from_value_collect`
+pub trait Located: From<Error> {
+    /// Places the errors one level deeper into the input: the path of an error that already has one is prefixed
+    /// with `segment`, any other error is wrapped together with the expected type and the JS `typeof` of the value
+    #[must_use]
+    fn at(self, segment: PathSegment, expected: &'static str, actual: impl FnOnce() -> String) -> Self;
+}
+
+impl Located for Error {
+    fn at(self, segment: PathSegment, expected: &'static str, actual: impl FnOnce() -> String) -> Self {
+        match self {
+            Self::AtPath {
+                mut path,
+                expected,
+                actual,
+                source,
+            } => {
+                path.0.insert(0, segment);
+                Self::AtPath {
+                    path,
+                    expected,
+                    actual,
+                    source,
+                }
+            }
+            source => Self::AtPath {
+                path: Path(vec![segment]),
+                expected,
+                actual: actual(),
+                source: Box::new(source),
+            },
+        }
+    }
+}
+
+impl Located for Vec<Error> {
+    fn at(self, segment: PathSegment, expected: &'static str, actual: impl FnOnce() -> String) -> Self {
+        let actual = self.iter().any(|error| error.path().is_none()).then(actual);
+        self.into_iter()
+            .map(|error| error.at(segment.clone(), expected, || actual.clone().unwrap_or_default()))
+            .collect()
+    }
+}
+
+impl From<Error> for Vec<Error> {
+    fn from(error: Error) -> Self {
+        vec![error]
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -3,15 +3,46 @@
 #[cfg(feature = "json")]
 use crate::json::v8_to_json_value;
 use crate::{
-    errors,
+    buffer::{copy_bytes, BufferView, ByteBuf, Float32Vec, Float64Vec, Int32Vec},
+    date, duration, errors,
     helpers::{
-        try_as_bool, try_as_f32, try_as_f64, try_as_hashmap, try_as_i32, try_as_i64, try_as_i8, try_as_string,
-        try_as_u32,
+        check_length, collect_errors, get_element_as, try_as_bool, try_as_char, try_as_f32, try_as_f64, try_as_i32,
+        try_as_i8, try_as_integer_with, try_as_map_collect, try_as_map_with, try_as_parsed, try_as_sequence_collect,
+        try_as_sequence_with, try_as_set_collect, try_as_set_with, try_as_string, try_as_u32, try_as_vec_collect,
+        try_as_vec_with,
     },
-    try_as_vec,
+    key::MapKey,
+    value::{self, JsValue},
 };
 use deno_core::v8;
-use std::{collections::HashMap, hash::BuildHasher};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::OsString,
+    hash::{BuildHasher, Hash},
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+
+/// How values of a different JS type are treated during a conversion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConversionMode {
+    /// Coerce values the way JS does: anything converts to a `String`, `"false"` is `true`, `null` is `0`
+    #[default]
+    Lenient,
+    /// Refuse values of another JS type, e.g. a number where a string is expected
+    Strict,
+}
+
+impl ConversionMode {
+    #[must_use]
+    pub fn is_strict(self) -> bool {
+        self == Self::Strict
+    }
+}
 
 /// The `TryFromValue` trait is used to convert a `v8::Value` into a Rust type.
 pub trait TryFromValue {
@@ -25,6 +56,54 @@ pub trait TryFromValue {
     ) -> errors::Result<Self>
     where
         Self: Sized;
+
+    /// Converts a `v8::Value` into a Rust type using the given conversion mode.
+    /// `try_from_value` is the same as converting in `ConversionMode::Lenient`.
+    ///
+    /// # Errors
+    /// In case of conversion errors, or if the value is not supported, an error is returned.
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self>
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::try_from_value(input, scope)
+    }
+
+    /// Converts a `v8::Value` into a Rust type, carrying on past the first error; the errors of all fields,
+    /// array elements and map entries are returned, each with its path.
+    ///
+    /// # Errors
+    /// Every conversion error found in the value.
+    fn try_from_value_collect<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> Result<Self, Vec<errors::Error>>
+    where
+        Self: Sized,
+    {
+        Self::try_from_value_collect_with(input, scope, ConversionMode::Lenient)
+    }
+
+    /// Like `try_from_value_collect`, using the given conversion mode.
+    /// Types without parts of their own report their single error.
+    ///
+    /// # Errors
+    /// Every conversion error found in the value.
+    fn try_from_value_collect_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self, Vec<errors::Error>>
+    where
+        Self: Sized,
+    {
+        Self::try_from_value_with(input, scope, mode).map_err(|error| vec![error])
+    }
 }
 
 impl<T> TryFromValue for Vec<T>
@@ -35,23 +114,184 @@ where
         input: &'a v8::Local<'a, v8::Value>,
         scope: &'a mut v8::HandleScope<'_, v8::Context>,
     ) -> errors::Result<Self> {
-        try_as_vec(input, scope)
+        try_as_vec_with(input, scope, ConversionMode::Lenient)
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        try_as_vec_with(input, scope, mode)
+    }
+
+    fn try_from_value_collect_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self, Vec<errors::Error>> {
+        try_as_vec_collect(input, scope, mode)
     }
 }
 
-impl<T, S> TryFromValue for HashMap<String, T, S>
+impl<T, const N: usize> TryFromValue for [T; N]
 where
     T: TryFromValue,
-    S: BuildHasher + Default,
 {
     fn try_from_value<'a>(
         input: &'a v8::Local<'a, v8::Value>,
         scope: &'a mut v8::HandleScope<'_, v8::Context>,
     ) -> errors::Result<Self> {
-        try_as_hashmap(input, scope)
+        Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        check_length(input, N)?;
+        let elements: Vec<T> = try_as_vec_with(input, scope, mode)?;
+        let actual = elements.len();
+        elements
+            .try_into()
+            .map_err(|_| errors::Error::LengthMismatch { expected: N, actual })
+    }
+
+    fn try_from_value_collect_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self, Vec<errors::Error>> {
+        check_length(input, N)?;
+        let elements: Vec<T> = try_as_vec_collect(input, scope, mode)?;
+        let actual = elements.len();
+        elements
+            .try_into()
+            .map_err(|_| vec![errors::Error::LengthMismatch { expected: N, actual }])
     }
 }
 
+/// Implements `TryFromValue` for tuples, read from an Array with one element per field
+macro_rules! impl_try_from_tuple {
+    ($($len:literal => ($($index:tt $name:ident),+)),*) => {
+        $(
+            impl<$($name),+> TryFromValue for ($($name,)+)
+            where
+                $($name: TryFromValue),+
+            {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    check_length(input, $len)?;
+                    Ok(($(
+                        get_element_as($index, input, scope, |input, scope| {
+                            $name::try_from_value_with(input, scope, mode)
+                        })?,
+                    )+))
+                }
+
+                // the elements are bound to the names of their type parameters
+                #[allow(non_snake_case)]
+                fn try_from_value_collect_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> Result<Self, Vec<errors::Error>> {
+                    check_length(input, $len)?;
+                    let mut errors = Vec::new();
+                    let elements = ($(
+                        collect_errors(
+                            &mut errors,
+                            get_element_as($index, input, scope, |input, scope| {
+                                $name::try_from_value_collect_with(input, scope, mode)
+                            }),
+                        ),
+                    )+);
+                    match elements {
+                        ($(Some($name),)+) => Ok(($($name,)+)),
+                        _ => Err(errors),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_tuple! {
+    1 => (0 T0),
+    2 => (0 T0, 1 T1),
+    3 => (0 T0, 1 T1, 2 T2),
+    4 => (0 T0, 1 T1, 2 T2, 3 T3),
+    5 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4),
+    6 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5),
+    7 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6),
+    8 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7),
+    9 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8),
+    10 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9),
+    11 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10),
+    12 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11)
+}
+
+/// Implements `TryFromValue` for a collection converted by a pair of helpers: `$with` converts until the first
+/// error, `$collect` converts everything and gathers the errors
+macro_rules! impl_try_from_collection {
+    ($($(#[$attr:meta])* [$($param:ident),+] $t:ty where [$($bound:tt)+] => $with:ident, $collect:ident;)*) => {
+        $(
+            $(#[$attr])*
+            impl<$($param),+> TryFromValue for $t
+            where
+                $($bound)+
+            {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    $with(input, scope, ConversionMode::Lenient)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    $with(input, scope, mode)
+                }
+
+                fn try_from_value_collect_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> Result<Self, Vec<errors::Error>> {
+                    $collect(input, scope, mode)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_collection! {
+    [T] VecDeque<T> where [T: TryFromValue] => try_as_sequence_with, try_as_sequence_collect;
+    [T] LinkedList<T> where [T: TryFromValue] => try_as_sequence_with, try_as_sequence_collect;
+    [T, S] HashSet<T, S> where [T: TryFromValue + Eq + Hash, S: BuildHasher + Default] => try_as_set_with, try_as_set_collect;
+    [T] BTreeSet<T> where [T: TryFromValue + Ord] => try_as_set_with, try_as_set_collect;
+    [K, T, S] HashMap<K, T, S> where [K: MapKey + Eq + Hash, T: TryFromValue, S: BuildHasher + Default]
+        => try_as_map_with, try_as_map_collect;
+    [K, T] BTreeMap<K, T> where [K: MapKey + Ord, T: TryFromValue] => try_as_map_with, try_as_map_collect;
+    #[cfg(feature = "indexmap")]
+    [K, T, S] indexmap::IndexMap<K, T, S> where [K: MapKey + Eq + Hash, T: TryFromValue, S: BuildHasher + Default]
+        => try_as_map_with, try_as_map_collect;
+}
+
 impl<T> TryFromValue for Option<T>
 where
     T: TryFromValue,
@@ -60,15 +300,321 @@ where
         input: &'a v8::Local<'a, v8::Value>,
         scope: &'a mut v8::HandleScope<'_, v8::Context>,
     ) -> errors::Result<Self> {
+        Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        if input.is_null_or_undefined() {
+            return Ok(None);
+        }
+
+        let value = T::try_from_value_with(input, scope, mode)?;
+        Ok(Some(value))
+    }
+
+    fn try_from_value_collect_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self, Vec<errors::Error>> {
         if input.is_null_or_undefined() {
             return Ok(None);
         }
 
-        let value = T::try_from_value(input, scope)?;
+        let value = T::try_from_value_collect_with(input, scope, mode)?;
         Ok(Some(value))
     }
 }
 
+/// Implements `TryFromValue` for types converted as `$inner` and then wrapped with `From`
+macro_rules! impl_try_from_wrapper {
+    ($([$($param:ident),*] $t:ty => $inner:ty;)*) => {
+        $(
+            impl<$($param: TryFromValue),*> TryFromValue for $t {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    <$inner>::try_from_value(input, scope).map(Into::into)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    <$inner>::try_from_value_with(input, scope, mode).map(Into::into)
+                }
+
+                fn try_from_value_collect_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> Result<Self, Vec<errors::Error>> {
+                    <$inner>::try_from_value_collect_with(input, scope, mode).map(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_wrapper! {
+    [T] Box<T> => T;
+    [T] Rc<T> => T;
+    [T] Arc<T> => T;
+    [] Box<str> => String;
+    [] Rc<str> => String;
+    [] Arc<str> => String;
+    [] PathBuf => String;
+    [] OsString => String;
+}
+
+impl<B> TryFromValue for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+    B::Owned: TryFromValue,
+{
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        B::Owned::try_from_value(input, scope).map(Cow::Owned)
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        B::Owned::try_from_value_with(input, scope, mode).map(Cow::Owned)
+    }
+
+    fn try_from_value_collect_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self, Vec<errors::Error>> {
+        B::Owned::try_from_value_collect_with(input, scope, mode).map(Cow::Owned)
+    }
+}
+
+impl TryFromValue for ByteBuf {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+    }
+
+    /// Lenient conversions also accept an Array of Numbers
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        if input.is_array() && !mode.is_strict() {
+            return try_as_vec_with(input, scope, mode).map(Self);
+        }
+        copy_bytes(*input).map(Self)
+    }
+}
+
+impl TryFromValue for BufferView<'_> {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        Self::view(*input, scope)
+    }
+}
+
+/// Implements `TryFromValue` for the vectors backed by typed arrays
+macro_rules! impl_try_from_typed_vec {
+    ($($t:ident),*) => {
+        $(
+            impl TryFromValue for $t {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+                }
+
+                /// Lenient conversions also accept an Array of Numbers
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    if input.is_array() && !mode.is_strict() {
+                        return try_as_vec_with(input, scope, mode).map(Self);
+                    }
+                    if !Self::is(input) {
+                        return Err(errors::Error::ExpectedBuffer(Self::JS_TYPE));
+                    }
+                    copy_bytes(*input).map(|bytes| Self::from_bytes(&bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_typed_vec!(Float32Vec, Float64Vec, Int32Vec);
+
+#[cfg(feature = "bytes")]
+impl TryFromValue for bytes::Bytes {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        ByteBuf::try_from_value(input, scope).map(|bytes| bytes.0.into())
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        ByteBuf::try_from_value_with(input, scope, mode).map(|bytes| bytes.0.into())
+    }
+}
+
+/// Implements `TryFromValue` for types parsed from their string representation, failing with `Error::$error`
+macro_rules! impl_try_from_str {
+    ($($(#[$attr:meta])* $t:ty => $error:ident),*) => {
+        $(
+            $(#[$attr])*
+            impl TryFromValue for $t {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    try_as_parsed(input, scope, ConversionMode::Lenient, errors::Error::$error)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    try_as_parsed(input, scope, mode, errors::Error::$error)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_str! {
+    IpAddr => InvalidIpAddr,
+    SocketAddr => InvalidSocketAddr,
+    #[cfg(feature = "uuid")]
+    uuid::Uuid => InvalidUuid,
+    #[cfg(feature = "url")]
+    url::Url => InvalidUrl
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFromValue for rust_decimal::Decimal {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        Self::try_from_value_with(input, scope, ConversionMode::Lenient)
+    }
+
+    /// Parses a String; Numbers are accepted as well unless converting in strict mode, exactly as JS prints them
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        if !(input.is_string() || input.is_number() && !mode.is_strict()) {
+            return Err(errors::Error::ExpectedString);
+        }
+
+        // Numbers print in scientific notation when very large or small, like 1e-7
+        let text = input.to_rust_string_lossy(scope);
+        text.parse()
+            .or_else(|_| Self::from_scientific(&text))
+            .map_err(|error| errors::Error::InvalidDecimal(format!("{text:?}: {error}")))
+    }
+}
+
+/// Implements `TryFromValue` for dates and times read from a Date, an ISO 8601 string or a Number
+macro_rules! impl_try_from_date {
+    ($($(#[$attr:meta])* $t:ty),*) => {
+        $(
+            $(#[$attr])*
+            impl TryFromValue for $t {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    date::try_from_value_with(input, scope, ConversionMode::Lenient)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    date::try_from_value_with(input, scope, mode)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_date! {
+    std::time::SystemTime,
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::Utc>,
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::FixedOffset>,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveTime,
+    #[cfg(feature = "time")]
+    time::OffsetDateTime,
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime,
+    #[cfg(feature = "time")]
+    time::Date
+}
+
+impl TryFromValue for Duration {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        duration::try_from_value_with(input, scope, ConversionMode::Lenient)
+    }
+
+    fn try_from_value_with<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> errors::Result<Self> {
+        duration::try_from_value_with(input, scope, mode)
+    }
+}
+
+impl TryFromValue for JsValue {
+    fn try_from_value<'a>(
+        input: &'a v8::Local<'a, v8::Value>,
+        scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    ) -> errors::Result<Self> {
+        value::try_from_value(*input, scope)
+    }
+}
+
 #[cfg(feature = "json")]
 impl TryFromValue for serde_json::Value {
     fn try_from_value<'a>(
@@ -80,8 +626,9 @@ impl TryFromValue for serde_json::Value {
     }
 }
 
+/// Implements `TryFromValue` with `$func`; in strict mode the value must also pass the `$is` check
 macro_rules! impl_try_from_value {
-    ($($t:ty => $func:ident),*) => {
+    ($($t:ty => $func:ident if $is:ident else $error:ident),*) => {
         $(
             impl TryFromValue for $t {
                 fn try_from_value<'a>(
@@ -90,25 +637,62 @@ macro_rules! impl_try_from_value {
                 ) -> errors::Result<Self> {
                     $func(input, scope)
                 }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    if mode.is_strict() && !input.$is() {
+                        return Err(errors::Error::$error);
+                    }
+                    $func(input, scope)
+                }
             }
         )*
     };
 }
 
 impl_try_from_value! {
-    bool => try_as_bool,
-    String => try_as_string,
-    i8 => try_as_i8,
-    i32 => try_as_i32,
-    i64 => try_as_i64,
-    f64 => try_as_f64,
-    u32 => try_as_u32,
-    f32 => try_as_f32
+    bool => try_as_bool if is_boolean else ExpectedBoolean,
+    String => try_as_string if is_string else ExpectedString,
+    char => try_as_char if is_string else ExpectedString,
+    i8 => try_as_i8 if is_int32 else ExpectedI32,
+    i32 => try_as_i32 if is_int32 else ExpectedI32,
+    f64 => try_as_f64 if is_number else ExpectedF64,
+    u32 => try_as_u32 if is_uint32 else ExpectedU32,
+    f32 => try_as_f32 if is_number else ExpectedF64
 }
 
+/// Implements `TryFromValue` for integers read from either a Number or a `BigInt`
+macro_rules! impl_try_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl TryFromValue for $t {
+                fn try_from_value<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                ) -> errors::Result<Self> {
+                    try_as_integer_with(input, scope, ConversionMode::Lenient)
+                }
+
+                fn try_from_value_with<'a>(
+                    input: &'a v8::Local<'a, v8::Value>,
+                    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> errors::Result<Self> {
+                    try_as_integer_with(input, scope, mode)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_integer!(u8, u16, i16, i64, u64, usize, isize, i128, u128);
+
 #[cfg(test)]
 mod tests {
-    use crate::{self as v8_derive, from::TryFromValue, setup};
+    use crate::{errors::Error, from::TryFromValue, setup, ConversionMode};
     use deno_core::v8;
     use deno_core::v8::{ContextOptions, CreateParams, Local, Value};
     use std::collections::HashMap;
@@ -138,6 +722,90 @@ mod tests {
         vec: Vec<i32>,
     }
 
+    type Scores = Vec<i32>;
+
+    #[derive(Debug, FromValue)]
+    struct LooseObject {
+        name: String,
+        flag: bool,
+    }
+
+    #[derive(Debug, FromValue)]
+    #[v8(strict)]
+    struct StrictObject {
+        name: String,
+        #[v8(lenient)]
+        label: String,
+    }
+
+    #[derive(FromValue)]
+    struct QualifiedObject {
+        name: std::string::String,
+        scores: Scores,
+        tags: Option<Vec<String>>,
+    }
+
+    #[derive(Debug, FromValue)]
+    #[v8(rename_all = "camelCase")]
+    struct RenamedObject {
+        first_name: String,
+        #[v8(rename = "years")]
+        age: i32,
+        #[v8(alias = "mail", alias = "email_address")]
+        email: String,
+    }
+
+    fn default_port() -> u32 {
+        8080
+    }
+
+    #[derive(Debug, FromValue)]
+    struct DefaultFields {
+        name: String,
+        #[v8(default)]
+        retries: i32,
+        #[v8(default = "default_port")]
+        port: u32,
+        #[v8(default)]
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, FromValue)]
+    #[v8(default)]
+    struct DefaultContainer {
+        enabled: bool,
+        level: i32,
+    }
+
+    impl Default for DefaultContainer {
+        fn default() -> Self {
+            Self {
+                enabled: true,
+                level: 3,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromValue)]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    #[derive(Debug, PartialEq, FromValue)]
+    enum Message {
+        Quit,
+        Move { x: i32, y: i32 },
+        Write(String),
+    }
+
+    #[derive(Debug, PartialEq, FromValue)]
+    #[v8(strict)]
+    enum Shape {
+        Rect { width: u32, height: u32 },
+        Segment(i32, i32),
+    }
+
     #[test]
     fn should_be_able_to_handle_incomplete_values() {
         setup::setup_test();
@@ -347,6 +1015,205 @@ mod tests {
         assert!(s.yes_no);
     }
 
+    #[test]
+    fn should_refuse_coercion_in_strict_mode() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // { name: 42, flag: "false", label: 7 }
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::Integer::new(scope, 42).into();
+        object.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "flag").unwrap().into();
+        let js_val = v8::String::new(scope, "false").unwrap().into();
+        object.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "label").unwrap().into();
+        let js_val = v8::Integer::new(scope, 7).into();
+        object.set(scope, js_key, js_val);
+        let object: Local<'_, Value> = object.cast();
+
+        // lenient by default
+        let loose = LooseObject::try_from_value(&object, scope).expect("lenient conversion");
+        assert_eq!(loose.name, "42");
+        assert!(loose.flag);
+
+        // strict per call
+        let error = LooseObject::try_from_value_with(&object, scope, ConversionMode::Strict).unwrap_err();
+        assert_eq!(error.path().map(ToString::to_string).as_deref(), Some("name"));
+
+        // strict through the container attribute, regardless of the mode of the call
+        let error = StrictObject::try_from_value(&object, scope).unwrap_err();
+        assert_eq!(error.path().map(ToString::to_string).as_deref(), Some("name"));
+
+        // the field attribute takes precedence over the container
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::String::new(scope, "Alice").unwrap().into();
+        object.to_object(scope).unwrap().set(scope, js_key, js_val);
+        let strict = StrictObject::try_from_value(&object, scope).expect("label is lenient");
+        assert_eq!(strict.name, "Alice");
+        assert_eq!(strict.label, "7");
+    }
+
+    #[test]
+    fn should_report_the_path_of_a_failing_field() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // [{ nested: { age: 30 } }, { nested: { age: Symbol() } }]
+        let array = v8::Array::new(scope, 2);
+        for (i, valid) in [(0, true), (1, false)] {
+            let object = v8::Object::new(scope);
+            let js_key = v8::String::new(scope, "age").unwrap().into();
+            let js_val = if valid {
+                v8::Integer::new(scope, 30).into()
+            } else {
+                v8::Symbol::new(scope, None).into()
+            };
+            object.set(scope, js_key, js_val);
+
+            let parent_object = v8::Object::new(scope);
+            let js_key = v8::String::new(scope, "nested").unwrap().into();
+            parent_object.set(scope, js_key, object.into());
+            array.set_index(scope, i, parent_object.into());
+        }
+        let array: Local<'_, Value> = array.cast();
+
+        let error = Vec::<ParentObject>::try_from_value(&array, scope).expect_err("age is a symbol");
+        assert_eq!(error.path().map(ToString::to_string).as_deref(), Some("[1].nested.age"));
+        let Error::AtPath { expected, actual, .. } = &error else {
+            panic!("expected an error with a path, got {error:?}");
+        };
+        assert_eq!(*expected, "i32");
+        assert_eq!(actual, "symbol");
+        assert!(error.to_string().contains("[1].nested.age"));
+    }
+
+    #[test]
+    fn should_collect_every_conversion_error() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // [{ name: 1, flag: "yes", label: "ok" }, { name: "Bob", flag: true, label: "ok" }, { label: 2 }]
+        let array = v8::Array::new(scope, 3);
+        let first = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::Integer::new(scope, 1).into();
+        first.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "flag").unwrap().into();
+        let js_val = v8::String::new(scope, "yes").unwrap().into();
+        first.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "label").unwrap().into();
+        let js_val = v8::String::new(scope, "ok").unwrap().into();
+        first.set(scope, js_key, js_val);
+        array.set_index(scope, 0, first.into());
+
+        let second = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::String::new(scope, "Bob").unwrap().into();
+        second.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "flag").unwrap().into();
+        let js_val = v8::Boolean::new(scope, true).into();
+        second.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "label").unwrap().into();
+        let js_val = v8::String::new(scope, "ok").unwrap().into();
+        second.set(scope, js_key, js_val);
+        array.set_index(scope, 1, second.into());
+
+        let third = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "label").unwrap().into();
+        let js_val = v8::Integer::new(scope, 2).into();
+        third.set(scope, js_key, js_val);
+        array.set_index(scope, 2, third.into());
+        let array: Local<'_, Value> = array.cast();
+
+        // the first error only
+        let error = Vec::<LooseObject>::try_from_value_with(&array, scope, ConversionMode::Strict).unwrap_err();
+        assert_eq!(error.path().map(ToString::to_string).as_deref(), Some("[0].name"));
+
+        let errors =
+            Vec::<LooseObject>::try_from_value_collect_with(&array, scope, ConversionMode::Strict).unwrap_err();
+        let paths: Vec<_> = errors
+            .iter()
+            .filter_map(|error| error.path().map(ToString::to_string))
+            .collect();
+        assert_eq!(paths, ["[0].name", "[0].flag", "[2].name", "[2].flag"]);
+
+        // tuples collect the errors of every element as well
+        let errors = <(LooseObject, LooseObject, LooseObject)>::try_from_value_collect_with(
+            &array,
+            scope,
+            ConversionMode::Strict,
+        )
+        .unwrap_err();
+        let paths: Vec<_> = errors
+            .iter()
+            .filter_map(|error| error.path().map(ToString::to_string))
+            .collect();
+        assert_eq!(paths, ["[0].name", "[0].flag", "[2].name", "[2].flag"]);
+
+        // lenient conversions coerce every value, including the missing ones
+        let collected = Vec::<LooseObject>::try_from_value_collect(&array, scope).expect("lenient conversion");
+        assert_eq!(collected.len(), 3);
+    }
+
+    #[test]
+    fn should_collect_every_conversion_error_of_a_variant() {
+        setup::with_scope(|scope| {
+            // { Rect: { width: "wide", height: "high" } }
+            let content = v8::Object::new(scope);
+            let js_key = v8::String::new(scope, "width").unwrap().into();
+            let js_val = v8::String::new(scope, "wide").unwrap().into();
+            content.set(scope, js_key, js_val);
+            let js_key = v8::String::new(scope, "height").unwrap().into();
+            let js_val = v8::String::new(scope, "high").unwrap().into();
+            content.set(scope, js_key, js_val);
+            let object = v8::Object::new(scope);
+            let js_key = v8::String::new(scope, "Rect").unwrap().into();
+            object.set(scope, js_key, content.into());
+            let value: Local<'_, Value> = object.into();
+
+            let error = Shape::try_from_value(&value, scope).unwrap_err();
+            assert_eq!(error.path().map(ToString::to_string).as_deref(), Some("width"));
+            let errors = Shape::try_from_value_collect(&value, scope).unwrap_err();
+            let paths: Vec<_> = errors
+                .iter()
+                .filter_map(|error| error.path().map(ToString::to_string))
+                .collect();
+            assert_eq!(paths, ["width", "height"]);
+
+            // { Segment: [true, "end"] }
+            let start = v8::Boolean::new(scope, true).into();
+            let end = v8::String::new(scope, "end").unwrap().into();
+            let content = v8::Array::new_with_elements(scope, &[start, end]);
+            let object = v8::Object::new(scope);
+            let js_key = v8::String::new(scope, "Segment").unwrap().into();
+            object.set(scope, js_key, content.into());
+            let value: Local<'_, Value> = object.into();
+
+            let errors = Shape::try_from_value_collect(&value, scope).unwrap_err();
+            let paths: Vec<_> = errors
+                .iter()
+                .filter_map(|error| error.path().map(ToString::to_string))
+                .collect();
+            assert_eq!(paths, ["[0]", "[1]"]);
+
+            // an unknown variant is a single error
+            let value: Local<'_, Value> = v8::String::new(scope, "Circle").unwrap().into();
+            let errors = Shape::try_from_value_collect(&value, scope).unwrap_err();
+            assert!(matches!(errors.as_slice(), [Error::UnknownVariant(variant)] if variant == "Circle"));
+        });
+    }
+
     #[test]
     fn can_deserialize_an_object_with_a_vec() {
         setup::setup_test();
@@ -373,6 +1240,153 @@ mod tests {
         assert_eq!(p.vec, vec![1, 2, 3]);
     }
 
+    #[test]
+    fn should_parse_fields_by_type_regardless_of_path_or_alias() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::String::new(scope, "Alice").unwrap().into();
+        object.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "scores").unwrap().into();
+        let js_array = v8::Array::new(scope, 2);
+        let js_val_1 = v8::Integer::new(scope, 7);
+        js_array.set_index(scope, 0, js_val_1.into());
+        let js_val_2 = v8::Integer::new(scope, 9);
+        js_array.set_index(scope, 1, js_val_2.into());
+        object.set(scope, js_key, js_array.into());
+        let object: Local<'_, Value> = object.cast();
+
+        let p = QualifiedObject::try_from_value(&object, scope).expect("failed to deserialize");
+        assert_eq!(p.name, "Alice");
+        assert_eq!(p.scores, vec![7, 9]);
+        assert_eq!(p.tags, None);
+    }
+
+    #[test]
+    fn should_be_able_to_parse_renamed_and_aliased_fields() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let object = v8::Object::new(scope);
+        // firstName
+        let js_key = v8::String::new(scope, "firstName").unwrap().into();
+        let js_val = v8::String::new(scope, "Marcel").unwrap().into();
+        object.set(scope, js_key, js_val);
+        // years
+        let js_key = v8::String::new(scope, "years").unwrap().into();
+        let js_val = v8::Integer::new(scope, 30).into();
+        object.set(scope, js_key, js_val);
+        // mail
+        let js_key = v8::String::new(scope, "mail").unwrap().into();
+        let js_val = v8::String::new(scope, "marcel@example.com").unwrap().into();
+        object.set(scope, js_key, js_val);
+
+        let object: Local<'_, Value> = object.cast();
+        let s = RenamedObject::try_from_value(&object, scope).expect("failed to deserialize");
+        assert_eq!(s.first_name, "Marcel");
+        assert_eq!(s.age, 30);
+        assert_eq!(s.email, "marcel@example.com");
+    }
+
+    #[test]
+    fn should_use_defaults_for_missing_fields() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let object = v8::Object::new(scope);
+        // name
+        let js_key = v8::String::new(scope, "name").unwrap().into();
+        let js_val = v8::String::new(scope, "server").unwrap().into();
+        object.set(scope, js_key, js_val);
+        // retries; undefined counts as missing
+        let js_key = v8::String::new(scope, "retries").unwrap().into();
+        let js_val = v8::undefined(scope).into();
+        object.set(scope, js_key, js_val);
+
+        let object: Local<'_, Value> = object.cast();
+        let s = DefaultFields::try_from_value(&object, scope).expect("failed to deserialize");
+        assert_eq!(s.name, "server");
+        assert_eq!(s.retries, 0);
+        assert_eq!(s.port, 8080);
+        assert!(s.tags.is_empty());
+
+        // container default
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "level").unwrap().into();
+        let js_val = v8::Integer::new(scope, 5).into();
+        object.set(scope, js_key, js_val);
+
+        let object: Local<'_, Value> = object.cast();
+        let s = DefaultContainer::try_from_value(&object, scope).expect("failed to deserialize");
+        assert!(s.enabled);
+        assert_eq!(s.level, 5);
+    }
+
+    #[test]
+    fn should_be_able_to_parse_a_unit_enum_from_a_string() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let js_string_val = v8::String::new(scope, "Down").unwrap().into();
+        let direction = Direction::try_from_value(&js_string_val, scope).expect("failed to deserialize");
+        assert_eq!(direction, Direction::Down);
+
+        let js_string_val = v8::String::new(scope, "Left").unwrap().into();
+        Direction::try_from_value(&js_string_val, scope).expect_err("unknown variant");
+    }
+
+    #[test]
+    fn should_be_able_to_parse_an_externally_tagged_enum() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // unit variant
+        let js_string_val = v8::String::new(scope, "Quit").unwrap().into();
+        let message = Message::try_from_value(&js_string_val, scope).expect("failed to deserialize");
+        assert_eq!(message, Message::Quit);
+
+        // struct variant
+        let move_object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "x").unwrap().into();
+        let js_val = v8::Integer::new(scope, 1).into();
+        move_object.set(scope, js_key, js_val);
+        let js_key = v8::String::new(scope, "y").unwrap().into();
+        let js_val = v8::Integer::new(scope, 2).into();
+        move_object.set(scope, js_key, js_val);
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "Move").unwrap().into();
+        object.set(scope, js_key, move_object.into());
+        let object: Local<'_, Value> = object.cast();
+        let message = Message::try_from_value(&object, scope).expect("failed to deserialize");
+        assert_eq!(message, Message::Move { x: 1, y: 2 });
+
+        // newtype variant
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "Write").unwrap().into();
+        let js_val = v8::String::new(scope, "Hello").unwrap().into();
+        object.set(scope, js_key, js_val);
+        let object: Local<'_, Value> = object.cast();
+        let message = Message::try_from_value(&object, scope).expect("failed to deserialize");
+        assert_eq!(message, Message::Write("Hello".to_string()));
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn should_be_able_to_parse_a_simple_object_to_hashmap() {
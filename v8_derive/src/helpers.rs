@@ -1,19 +1,24 @@
-use crate::{errors, from::TryFromValue};
+use crate::{
+    errors::{self, Located, PathSegment},
+    from::{ConversionMode, TryFromValue},
+    key::MapKey,
+};
 use deno_core::v8;
 use deno_core::v8::GetPropertyNamesArgs;
-use std::{collections::HashMap, hash::BuildHasher};
+use std::{collections::HashMap, fmt, hash::BuildHasher, str::FromStr};
 
-pub fn get_field_as<'a, T>(
+/// Parses the property `field_name` of the object with `parse_fn`; `E` is `Error`, or `Vec<Error>` when collecting
+pub fn get_field_as<'a, T, E: Located>(
     field_name: &str,
     input: &'a v8::Local<'a, v8::Value>,
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
-    parse_fn: ParseFn<T>,
-) -> errors::Result<T> {
+    parse_fn: impl FnOnce(&v8::Local<'_, v8::Value>, &mut v8::HandleScope<'_>) -> Result<T, E>,
+) -> Result<T, E> {
     if !input.is_object() {
-        return Err(errors::Error::ExpectedObject);
+        return Err(errors::Error::ExpectedObject.into());
     }
 
-    let js_object: v8::Local<v8::Object> = input.try_cast()?;
+    let js_object: v8::Local<v8::Object> = input.try_cast().map_err(errors::Error::from)?;
     let js_key = v8::String::new(scope, field_name)
         .map(Into::into)
         .ok_or(errors::Error::InvalidField(field_name.to_string()))?;
@@ -21,14 +26,28 @@ pub fn get_field_as<'a, T>(
         .get(scope, js_key)
         .ok_or(errors::Error::FieldNotFound(field_name.to_string()))?;
 
-    parse_fn(&js_value, scope)
+    parse_at(PathSegment::Field(field_name.to_string()), js_value, scope, parse_fn)
+}
+
+/// Parses a part of the input, recording where it sits when the conversion fails
+fn parse_at<T, E: Located>(
+    segment: PathSegment,
+    js_value: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    parse_fn: impl FnOnce(&v8::Local<'_, v8::Value>, &mut v8::HandleScope<'_>) -> Result<T, E>,
+) -> Result<T, E> {
+    parse_fn(&js_value, scope).map_err(|error| {
+        error.at(segment, std::any::type_name::<T>(), || {
+            js_value.type_of(scope).to_rust_string_lossy(scope)
+        })
+    })
 }
 
 pub fn get_optional_field_as<'a, T>(
     field_name: &str,
     input: &'a v8::Local<'a, v8::Value>,
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
-    parse_fn: ParseFn<T>,
+    parse_fn: impl FnOnce(&v8::Local<'_, v8::Value>, &mut v8::HandleScope<'_>) -> errors::Result<T>,
 ) -> errors::Result<Option<T>> {
     if !input.is_object() {
         return Err(errors::Error::ExpectedObject);
@@ -50,12 +69,201 @@ pub fn get_optional_field_as<'a, T>(
         return Ok(None);
     }
 
-    let inner_value = parse_fn(&js_value, scope)?;
+    let inner_value = parse_at(PathSegment::Field(field_name.to_string()), js_value, scope, parse_fn)?;
     Ok(Some(inner_value))
 }
 
+/// Like `get_field_as`, but absent or `undefined` properties are replaced by `default()`
+pub fn get_field_or_else<'a, T, E: Located>(
+    field_name: &str,
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    parse_fn: impl FnOnce(&v8::Local<'_, v8::Value>, &mut v8::HandleScope<'_>) -> Result<T, E>,
+    default: impl FnOnce() -> T,
+) -> Result<T, E> {
+    if !input.is_object() {
+        return Err(errors::Error::ExpectedObject.into());
+    }
+
+    let js_object: v8::Local<v8::Object> = input.try_cast().map_err(errors::Error::from)?;
+    let js_key = v8::String::new(scope, field_name)
+        .map(Into::into)
+        .ok_or(errors::Error::InvalidField(field_name.to_string()))?;
+
+    match js_object.get(scope, js_key) {
+        Some(js_value) if !js_value.is_undefined() => {
+            parse_at(PathSegment::Field(field_name.to_string()), js_value, scope, parse_fn)
+        }
+        _ => Ok(default()),
+    }
+}
+
+/// Returns the first of `names` that is present on the object; used for fields with aliases.
+/// Falls back to the first name, so the error reporting refers to the primary name.
+pub fn find_field_name<'n>(
+    names: &[&'n str],
+    input: &v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'_>,
+) -> &'n str {
+    let Ok(js_object) = input.try_cast::<v8::Object>() else {
+        return names[0];
+    };
+
+    names
+        .iter()
+        .find(|name| {
+            v8::String::new(scope, name)
+                .and_then(|js_key| js_object.get(scope, js_key.into()))
+                .is_some_and(|js_value| !js_value.is_undefined())
+        })
+        .unwrap_or(&names[0])
+}
+
 pub type ParseFn<T> = fn(&'_ v8::Local<'_, v8::Value>, &'_ mut v8::HandleScope<'_>) -> errors::Result<T>;
 
+pub fn get_element_as<'a, T, E: Located>(
+    index: u32,
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    parse_fn: impl FnOnce(&v8::Local<'_, v8::Value>, &mut v8::HandleScope<'_>) -> Result<T, E>,
+) -> Result<T, E> {
+    if !input.is_array() {
+        return Err(errors::Error::ExpectedArray.into());
+    }
+
+    let js_array: v8::Local<v8::Array> = input.try_cast().map_err(errors::Error::from)?;
+    let js_value = js_array
+        .get_index(scope, index)
+        .ok_or(errors::Error::FieldNotFound(index.to_string()))?;
+
+    parse_at(PathSegment::Index(index), js_value, scope, parse_fn)
+}
+
+/// Checks that the input is an Array of exactly `expected` elements, for tuples and fixed-size arrays
+pub fn check_length(input: &v8::Local<'_, v8::Value>, expected: usize) -> errors::Result<()> {
+    let array: v8::Local<v8::Array> = input.try_cast().map_err(|_| errors::Error::ExpectedArray)?;
+    let actual = array.length() as usize;
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(errors::Error::LengthMismatch { expected, actual })
+    }
+}
+
+/// Reads the variant name and content of an externally tagged enum.
+/// Unit variants are plain strings, all other variants are objects with the variant name as their only key.
+pub fn get_external_tag<'s>(
+    input: &v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+) -> errors::Result<(String, Option<v8::Local<'s, v8::Value>>)> {
+    if input.is_string() {
+        return Ok((input.to_rust_string_lossy(scope), None));
+    }
+
+    if !input.is_object() {
+        return Err(errors::Error::ExpectedVariant);
+    }
+
+    let js_object: v8::Local<v8::Object> = input.try_cast()?;
+    let keys = js_object
+        .get_own_property_names(scope, GetPropertyNamesArgs::default())
+        .ok_or(errors::Error::FailedToGetPropertyNames)?;
+
+    if keys.length() != 1 {
+        return Err(errors::Error::ExpectedVariant);
+    }
+
+    let key = keys
+        .get_index(scope, 0)
+        .ok_or(errors::Error::FailedToGetPropertyNames)?;
+    let content = js_object
+        .get(scope, key)
+        .ok_or(errors::Error::FailedToGetPropertyNames)?;
+
+    Ok((key.to_rust_string_lossy(scope), Some(content)))
+}
+
+/// Reads the variant name of an internally tagged enum from the `tag` property.
+pub fn get_tag(tag: &str, input: &v8::Local<'_, v8::Value>, scope: &mut v8::HandleScope<'_>) -> errors::Result<String> {
+    if !input.is_object() {
+        return Err(errors::Error::ExpectedObject);
+    }
+
+    let js_object: v8::Local<v8::Object> = input.try_cast()?;
+    let js_key = v8::String::new(scope, tag)
+        .map(Into::into)
+        .ok_or(errors::Error::InvalidField(tag.to_string()))?;
+
+    match js_object.get(scope, js_key) {
+        Some(js_value) if js_value.is_string() => Ok(js_value.to_rust_string_lossy(scope)),
+        Some(js_value) if !js_value.is_undefined() => Err(errors::Error::ExpectedString),
+        _ => Err(errors::Error::TagNotFound(tag.to_string())),
+    }
+}
+
+/// Reads the variant name and content of an adjacently tagged enum from the `tag` and `content` properties.
+pub fn get_adjacent_tag<'s>(
+    tag: &str,
+    content: &str,
+    input: &v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+) -> errors::Result<(String, Option<v8::Local<'s, v8::Value>>)> {
+    let tag = get_tag(tag, input, scope)?;
+
+    let js_object: v8::Local<v8::Object> = input.try_cast()?;
+    let js_key = v8::String::new(scope, content)
+        .map(Into::into)
+        .ok_or(errors::Error::InvalidField(content.to_string()))?;
+    let js_value = js_object.get(scope, js_key).filter(|js_value| !js_value.is_undefined());
+
+    Ok((tag, js_value))
+}
+
+pub fn require_content<'s>(
+    content: Option<v8::Local<'s, v8::Value>>,
+    variant: &str,
+) -> errors::Result<v8::Local<'s, v8::Value>> {
+    content.ok_or_else(|| errors::Error::ContentNotFound(variant.to_string()))
+}
+
+/// Runs a conversion inside its own `TryCatch`; used by the derives to try the variants of an untagged enum one
+/// by one, so that an exception thrown by one attempt, e.g. from a getter, does not leak into the next one.
+pub fn attempt<'s, T>(
+    scope: &mut v8::HandleScope<'s>,
+    conversion: impl FnOnce(&mut v8::HandleScope<'s>) -> errors::Result<T>,
+) -> errors::Result<T> {
+    let scope = &mut v8::TryCatch::new(scope);
+    let result = conversion(scope);
+    if scope.has_caught() {
+        scope.reset();
+    }
+
+    result
+}
+
+/// Sets the tag of an internally tagged newtype variant on its converted content; content that is not an object
+/// cannot carry the tag, so a `TypeError` is thrown on the scope and `undefined` returned instead
+pub fn set_tag<'s>(
+    tag: &str,
+    variant: &str,
+    content: v8::Local<'s, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+) -> v8::Local<'s, v8::Value> {
+    let Ok(object) = content.try_cast::<v8::Object>() else {
+        let message = errors::Error::ExpectedTaggableContent(variant.to_string()).to_string();
+        if let Some(message) = v8::String::new(scope, &message) {
+            let exception = v8::Exception::type_error(scope, message);
+            scope.throw_exception(exception);
+        }
+        return v8::undefined(scope).into();
+    };
+
+    if let (Some(js_key), Some(js_val)) = (v8::String::new(scope, tag), v8::String::new(scope, variant)) {
+        object.set(scope, js_key.into(), js_val.into());
+    }
+    content
+}
+
 pub fn try_as_bool<'a>(
     input: &'a v8::Local<'a, v8::Value>,
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
@@ -73,12 +281,54 @@ pub fn try_as_string<'a>(
     Ok(input.to_rust_string_lossy(scope))
 }
 
+/// Converts the value to a string, which must hold exactly one character
+pub fn try_as_char<'a>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+) -> errors::Result<char> {
+    let string = input.to_rust_string_lossy(scope);
+    let mut chars = string.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(errors::Error::ExpectedChar),
+    }
+}
+
+/// Parses the value as a string with `FromStr`, reporting failures with `invalid`; in strict mode the value must be
+/// a String
+pub fn try_as_parsed<'a, T>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+    invalid: fn(String) -> errors::Error,
+) -> errors::Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    if mode.is_strict() && !input.is_string() {
+        return Err(errors::Error::ExpectedString);
+    }
+
+    let text = input.to_rust_string_lossy(scope);
+    text.parse().map_err(|error| invalid(format!("{text:?}: {error}")))
+}
+
 pub fn try_as_i32<'a>(
     input: &'a v8::Local<'a, v8::Value>,
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
 ) -> errors::Result<i32> {
     // use the framework to get the internal convertion
-    input.int32_value(scope).ok_or(errors::Error::ExpectedI32)
+    let value = input.int32_value(scope).ok_or(errors::Error::ExpectedI32)?;
+    // which wraps Numbers beyond the range of an i32 around
+    if input.is_number() && !input.is_int32() {
+        let number = input.number_value(scope).unwrap_or_default();
+        let in_range = number > f64::from(i32::MIN) - 1.0 && number < f64::from(i32::MAX) + 1.0;
+        if number.is_finite() && !in_range {
+            return Err(errors::Error::OutOfRange(input.to_rust_string_lossy(scope)));
+        }
+    }
+    Ok(value)
 }
 
 pub fn try_as_u32<'a>(
@@ -93,16 +343,14 @@ pub fn try_as_u32<'a>(
     }
     // use the framework to get the internal conversion
     u32::try_from(input.to_big_int(scope).ok_or(errors::Error::ExpectedU32)?.i64_value().0)
-        .map_err(|_| errors::Error::OutOfRange)
+        .map_err(|_| errors::Error::OutOfRange(input.to_rust_string_lossy(scope)))
 }
 
 pub fn try_as_i64<'a>(
     input: &'a v8::Local<'a, v8::Value>,
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
 ) -> errors::Result<i64> {
-    // use the framework to get the internal convertion
-    let i = input.to_big_int(scope).ok_or(errors::Error::ExpectedI64)?;
-    Ok(i.i64_value().0)
+    try_as_integer_with(input, scope, ConversionMode::Lenient)
 }
 
 pub fn try_as_f64<'a>(
@@ -127,7 +375,97 @@ pub fn try_as_i8<'a>(
     scope: &'a mut v8::HandleScope<'_, v8::Context>,
 ) -> errors::Result<i8> {
     let i = try_as_i32(input, scope)?;
-    i8::try_from(i).map_err(|_| errors::Error::OutOfRange)
+    i8::try_from(i).map_err(|_| errors::Error::OutOfRange(i.to_string()))
+}
+
+/// The largest integer a JS Number represents exactly, `Number.MAX_SAFE_INTEGER`
+pub const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// Converts a Number or `BigInt` into the integer type `T`, failing with `OutOfRange` when the value does not fit.
+///
+/// Lenient conversions coerce like JS does: fractions are truncated, `null` and `undefined` are `0` and strings are
+/// parsed as Numbers. Strict conversions only accept a `BigInt` or a safe integer Number.
+pub fn try_as_integer_with<'a, T>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<T>
+where
+    T: TryFrom<i128> + TryFrom<u128>,
+{
+    let out_of_range =
+        |scope: &mut v8::HandleScope<'_, v8::Context>| errors::Error::OutOfRange(input.to_rust_string_lossy(scope));
+
+    if input.is_big_int() {
+        let big_int: v8::Local<v8::BigInt> = input.try_cast()?;
+        return big_int_parts(big_int)
+            .and_then(|(negative, magnitude)| integer_from_parts(negative, magnitude))
+            .ok_or_else(|| out_of_range(scope));
+    }
+
+    if mode.is_strict() && !input.is_number() {
+        return Err(errors::Error::ExpectedInteger);
+    }
+    if input.is_null_or_undefined() {
+        return integer_from_parts(false, 0).ok_or_else(|| out_of_range(scope));
+    }
+
+    let number = input.number_value(scope).ok_or(errors::Error::ExpectedInteger)?;
+    if number.is_nan() {
+        return Err(errors::Error::ExpectedInteger);
+    }
+    #[allow(clippy::cast_precision_loss)]
+    if mode.is_strict() && (number.fract() != 0.0 || number.abs() > MAX_SAFE_INTEGER as f64) {
+        return Err(out_of_range(scope));
+    }
+
+    // the casts saturate, so a value that does not survive the round trip is out of range
+    let number = number.trunc();
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss,
+        clippy::float_cmp
+    )]
+    let parts = if number < 0.0 {
+        let integer = number as i128;
+        (integer as f64 == number).then_some((true, integer.unsigned_abs()))
+    } else {
+        let integer = number as u128;
+        (integer as f64 == number).then_some((false, integer))
+    };
+
+    parts
+        .and_then(|(negative, magnitude)| integer_from_parts(negative, magnitude))
+        .ok_or_else(|| out_of_range(scope))
+}
+
+/// The sign and magnitude of a `BigInt`, `None` when it does not fit in 128 bits
+fn big_int_parts(big_int: v8::Local<'_, v8::BigInt>) -> Option<(bool, u128)> {
+    if big_int.word_count() > 2 {
+        return None;
+    }
+
+    let mut words = [0; 2];
+    let (negative, words) = big_int.to_words_array(&mut words);
+    let magnitude = words
+        .iter()
+        .rev()
+        .fold(0_u128, |magnitude, word| (magnitude << 64) | u128::from(*word));
+    Some((negative, magnitude))
+}
+
+fn integer_from_parts<T>(negative: bool, magnitude: u128) -> Option<T>
+where
+    T: TryFrom<i128> + TryFrom<u128>,
+{
+    if negative {
+        0_i128
+            .checked_sub_unsigned(magnitude)
+            .and_then(|integer| T::try_from(integer).ok())
+    } else {
+        T::try_from(magnitude).ok()
+    }
 }
 
 pub fn try_as_vec<'a, T>(
@@ -137,26 +475,153 @@ pub fn try_as_vec<'a, T>(
 where
     T: TryFromValue,
 {
+    try_as_vec_with(input, scope, ConversionMode::Lenient)
+}
+
+pub fn try_as_vec_with<'a, T>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<Vec<T>>
+where
+    T: TryFromValue,
+{
+    try_as_sequence_with(input, scope, mode)
+}
+
+/// Like `try_as_vec_with`, but converts every element and returns the errors of all of them
+pub fn try_as_vec_collect<'a, T>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<Vec<T>, Vec<errors::Error>>
+where
+    T: TryFromValue,
+{
+    try_as_sequence_collect(input, scope, mode)
+}
+
+/// Converts the elements of an Array into any collection, such as a `Vec` or `VecDeque`
+pub fn try_as_sequence_with<'a, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<C>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    let elements = elements(*input, scope)?;
+    convert_elements(elements, scope, mode)
+}
+
+/// Like `try_as_sequence_with`, but converts every element and returns the errors of all of them
+pub fn try_as_sequence_collect<'a, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<C, Vec<errors::Error>>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    let elements = elements(*input, scope)?;
+    collect_elements(elements, scope, mode)
+}
+
+/// Converts the values of a JS `Set` into any collection, such as a `HashSet` or `BTreeSet`;
+/// lenient conversions also accept an Array
+pub fn try_as_set_with<'a, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<C>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    let elements = set_elements(*input, scope, mode)?;
+    convert_elements(elements, scope, mode)
+}
+
+/// Like `try_as_set_with`, but converts every value and returns the errors of all of them
+pub fn try_as_set_collect<'a, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<C, Vec<errors::Error>>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    let elements = set_elements(*input, scope, mode)?;
+    collect_elements(elements, scope, mode)
+}
+
+fn convert_elements<T, C>(
+    elements: Vec<(u32, v8::Local<'_, v8::Value>)>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<C>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    elements
+        .into_iter()
+        .map(|(i, element)| {
+            parse_at(PathSegment::Index(i), element, scope, |element, scope| {
+                T::try_from_value_with(element, scope, mode)
+            })
+        })
+        .collect()
+}
+
+fn collect_elements<T, C>(
+    elements: Vec<(u32, v8::Local<'_, v8::Value>)>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<C, Vec<errors::Error>>
+where
+    T: TryFromValue,
+    C: FromIterator<T>,
+{
+    let results = elements.into_iter().map(|(i, element)| {
+        parse_at(PathSegment::Index(i), element, scope, |element, scope| {
+            T::try_from_value_collect_with(element, scope, mode)
+        })
+    });
+    collect_results(results)
+}
+
+/// The elements of an array together with their index
+fn elements<'s>(
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+) -> errors::Result<Vec<(u32, v8::Local<'s, v8::Value>)>> {
     if !input.is_array() {
         return Err(errors::Error::ExpectedArray);
     }
 
     let array: v8::Local<v8::Array> = input.try_cast()?;
-    let length = array.length();
-
-    let mut result = Vec::with_capacity(length as usize);
-
-    for i in 0..length {
-        let Some(element) = array.get_index(scope, i) else {
-            // this should never happen
-            continue;
-        };
+    // an element that can not be read should never happen, and is skipped
+    Ok((0..array.length())
+        .filter_map(|i| array.get_index(scope, i).map(|element| (i, element)))
+        .collect())
+}
 
-        let element = T::try_from_value(&element, scope)?;
-        result.push(element);
+/// The values of a `Set` in insertion order, indexed by their position
+fn set_elements<'s>(
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+    mode: ConversionMode,
+) -> errors::Result<Vec<(u32, v8::Local<'s, v8::Value>)>> {
+    if input.is_array() && !mode.is_strict() {
+        return elements(input, scope);
     }
 
-    Ok(result)
+    let set: v8::Local<v8::Set> = input.try_cast().map_err(|_| errors::Error::ExpectedSet)?;
+    elements(set.as_array(scope).into(), scope)
 }
 
 pub fn try_as_hashmap<'a, T, S>(
@@ -167,11 +632,110 @@ where
     T: TryFromValue,
     S: BuildHasher + Default,
 {
+    try_as_hashmap_with(input, scope, ConversionMode::Lenient)
+}
+
+pub fn try_as_hashmap_with<'a, T, S>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<HashMap<String, T, S>>
+where
+    T: TryFromValue,
+    S: BuildHasher + Default,
+{
+    try_as_map_with(input, scope, mode)
+}
+
+/// Like `try_as_hashmap_with`, but converts every entry and returns the errors of all of them
+pub fn try_as_hashmap_collect<'a, T, S>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<HashMap<String, T, S>, Vec<errors::Error>>
+where
+    T: TryFromValue,
+    S: BuildHasher + Default,
+{
+    try_as_map_collect(input, scope, mode)
+}
+
+/// Converts the entries of a `Map`, or the own properties of an object, into any map, such as a `HashMap` or
+/// `BTreeMap`; entries are visited in JS order
+pub fn try_as_map_with<'a, K, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> errors::Result<C>
+where
+    K: MapKey,
+    T: TryFromValue,
+    C: FromIterator<(K, T)>,
+{
+    let key_mode = map_key_mode(*input, mode);
+    entries(*input, scope)?
+        .into_iter()
+        .map(|(key, value)| {
+            let segment = PathSegment::Key(key.to_rust_string_lossy(scope));
+            let key = parse_at(segment.clone(), key, scope, |key, scope| {
+                K::try_from_key(*key, scope, key_mode)
+            })?;
+            let value = parse_at(segment, value, scope, |value, scope| {
+                T::try_from_value_with(value, scope, mode)
+            })?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Like `try_as_map_with`, but converts every entry and returns the errors of all of them
+pub fn try_as_map_collect<'a, K, T, C>(
+    input: &'a v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<C, Vec<errors::Error>>
+where
+    K: MapKey,
+    T: TryFromValue,
+    C: FromIterator<(K, T)>,
+{
+    let key_mode = map_key_mode(*input, mode);
+    let results = entries(*input, scope)?.into_iter().map(|(key, value)| {
+        let segment = PathSegment::Key(key.to_rust_string_lossy(scope));
+        let key = parse_at(segment.clone(), key, scope, |key, scope| {
+            K::try_from_key(*key, scope, key_mode).map_err(Vec::from)
+        });
+        let value = parse_at(segment, value, scope, |value, scope| {
+            T::try_from_value_collect_with(value, scope, mode)
+        });
+        match (key, value) {
+            (Ok(key), Ok(value)) => Ok((key, value)),
+            (key, value) => Err(key.err().into_iter().chain(value.err()).flatten().collect()),
+        }
+    });
+    collect_results(results)
+}
+
+/// The mode the keys of `input` convert in: property names are always strings, whatever the mode, while the keys of
+/// a `Map` are taken as they are
+pub(crate) fn map_key_mode(input: v8::Local<'_, v8::Value>, mode: ConversionMode) -> ConversionMode {
+    if input.is_map() {
+        mode
+    } else {
+        ConversionMode::Lenient
+    }
+}
+
+/// The entries of a `Map`, or the own properties of an object, with their keys as they are
+pub(crate) fn entries<'s>(
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'s>,
+) -> errors::Result<Vec<(v8::Local<'s, v8::Value>, v8::Local<'s, v8::Value>)>> {
     if !(input.is_map() || input.is_object()) {
         return Err(errors::Error::ExpectedMap);
     }
 
-    let mut result: HashMap<String, T, S> = HashMap::with_hasher(S::default());
+    let mut result = Vec::new();
 
     if input.is_map() {
         let js_map: v8::Local<v8::Map> = input.try_cast()?;
@@ -181,9 +745,7 @@ where
                 continue;
             };
 
-            let key = key.to_rust_string_lossy(scope);
-            let value = T::try_from_value(&value, scope)?;
-            result.insert(key, value);
+            result.push((key, value));
         }
 
         return Ok(result);
@@ -202,17 +764,45 @@ where
         let value = js_object
             .get(scope, key)
             .ok_or(errors::Error::FailedToGetPropertyNames)?;
-        let value = T::try_from_value(&value, scope)?;
-        let key = key.to_rust_string_lossy(scope);
-        result.insert(key, value);
+        result.push((key, value));
     }
 
     Ok(result)
 }
 
+/// Collects the successful results, or the errors of all failed ones
+pub fn collect_results<T, C: FromIterator<T>>(
+    results: impl IntoIterator<Item = Result<T, Vec<errors::Error>>>,
+) -> Result<C, Vec<errors::Error>> {
+    let mut errors = Vec::new();
+    let values: Vec<T> = results
+        .into_iter()
+        .filter_map(|result| result.map_err(|result_errors| errors.extend(result_errors)).ok())
+        .collect();
+
+    if errors.is_empty() {
+        Ok(values.into_iter().collect())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Keeps the value of a successful conversion, or adds its errors to `errors`; used by the derives to convert
+/// every field before reporting
+pub fn collect_errors<T>(errors: &mut Vec<errors::Error>, result: Result<T, Vec<errors::Error>>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(result_errors) => {
+            errors.extend(result_errors);
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod setup {
-    use super::{try_as_bool, try_as_i8};
+    use super::{try_as_bool, try_as_i8, try_as_integer_with};
+    use crate::{errors::Error, ConversionMode};
     use crate::{try_as_i32, try_as_u32};
     use deno_core::v8;
     use deno_core::v8::Value;
@@ -236,6 +826,16 @@ pub(crate) mod setup {
         });
     }
 
+    /// Runs `test` with a scope in a context of a new isolate
+    pub(crate) fn with_scope(test: impl FnOnce(&mut v8::HandleScope<'_>)) {
+        setup_test();
+        let isolate = &mut v8::Isolate::new(v8::CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, v8::ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+        test(scope);
+    }
+
     #[test]
     fn test_try_boolean() {
         // given
@@ -554,4 +1154,120 @@ pub(crate) mod setup {
         // - expect to be able to convert and result in false
         result.expect_err("Expected to NOT be able to convert");
     }
+
+    #[test]
+    fn test_try_i32_u32_i8_out_of_range() {
+        // given
+        // - v8 is all ok
+        setup_test();
+        let isolate = &mut v8::Isolate::new(v8::CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, v8::ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // given
+        // - a Number beyond i32::MAX
+        let value: v8::Local<'_, Value> = v8::Number::new(scope, 3e9).into();
+        // when
+        // - try to convert
+        let result = try_as_i32(&value, scope);
+        // then
+        // - expect it to be out of range instead of wrapping around, but to fit a u32
+        assert!(matches!(result, Err(Error::OutOfRange(value)) if value == "3000000000"));
+        assert_eq!(
+            3_000_000_000,
+            try_as_u32(&value, scope).expect("Expected to be able to convert")
+        );
+
+        // given
+        // - a Number beyond i8::MAX
+        let value: v8::Local<'_, Value> = v8::Number::new(scope, 300.0).into();
+        // when
+        // - try to convert
+        let result = try_as_i8(&value, scope);
+        // then
+        // - expect it to be out of range instead of wrapping around
+        assert!(matches!(result, Err(Error::OutOfRange(value)) if value == "300"));
+
+        // given
+        // - a negative Number
+        let value: v8::Local<'_, Value> = v8::Number::new(scope, -1.0).into();
+        // when
+        // - try to convert
+        let result = try_as_u32(&value, scope);
+        // then
+        // - expect to NOT be able to convert
+        result.expect_err("Expected to NOT be able to convert");
+
+        // given
+        // - a BigInt beyond u32::MAX
+        let value: v8::Local<'_, Value> = v8::BigInt::new_from_u64(scope, 1 << 32).into();
+        // when
+        // - try to convert
+        let result = try_as_u32(&value, scope);
+        // then
+        // - expect it to be out of range
+        assert!(matches!(result, Err(Error::OutOfRange(value)) if value == "4294967296"));
+    }
+
+    #[test]
+    fn test_try_integer_from_number_or_big_int() {
+        // given
+        // - v8 is all ok
+        setup_test();
+        let isolate = &mut v8::Isolate::new(v8::CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, v8::ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // given
+        // - a BigInt that needs more than 64 bits
+        let words = [0, 1];
+        let value: v8::Local<'_, Value> = v8::BigInt::new_from_words(scope, false, &words).unwrap().into();
+        // when
+        // - try to convert
+        let result = try_as_integer_with::<u128>(&value, scope, ConversionMode::Strict);
+        // then
+        // - expect to be able to convert
+        assert_eq!(1 << 64, result.expect("Expected to be able to convert"));
+        // - and to be out of range for a u64, reporting the value
+        let result = try_as_integer_with::<u64>(&value, scope, ConversionMode::Strict);
+        assert!(matches!(result, Err(Error::OutOfRange(value)) if value == "18446744073709551616"));
+
+        // given
+        // - a negative Number
+        let value: v8::Local<'_, Value> = v8::Number::new(scope, -300.0).into();
+        // when
+        // - try to convert
+        let result = try_as_integer_with::<i16>(&value, scope, ConversionMode::Strict);
+        // then
+        // - expect to be able to convert, but not into a u8
+        assert_eq!(-300, result.expect("Expected to be able to convert"));
+        let result = try_as_integer_with::<u8>(&value, scope, ConversionMode::Lenient);
+        assert!(matches!(result, Err(Error::OutOfRange(value)) if value == "-300"));
+
+        // given
+        // - a fraction
+        let value: v8::Local<'_, Value> = v8::Number::new(scope, 1.5).into();
+        // when
+        // - try to convert
+        let result = try_as_integer_with::<u8>(&value, scope, ConversionMode::Lenient);
+        // then
+        // - expect it to be truncated, unless converting strictly
+        assert_eq!(1, result.expect("Expected to be able to convert"));
+        try_as_integer_with::<u8>(&value, scope, ConversionMode::Strict)
+            .expect_err("Expected to NOT be able to convert");
+
+        // given
+        // - a numeric string
+        let value: v8::Local<'_, Value> = v8::String::new(scope, "42").unwrap().into();
+        // when
+        // - try to convert
+        let result = try_as_integer_with::<usize>(&value, scope, ConversionMode::Lenient);
+        // then
+        // - expect it to be coerced, unless converting strictly
+        assert_eq!(42, result.expect("Expected to be able to convert"));
+        let result = try_as_integer_with::<usize>(&value, scope, ConversionMode::Strict);
+        assert!(matches!(result, Err(Error::ExpectedInteger)));
+    }
 }
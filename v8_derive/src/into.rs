@@ -2,10 +2,24 @@
 
 #[cfg(feature = "json")]
 use crate::json::json_to_v8;
-#[cfg(feature = "chrono")]
-use chrono::DateTime;
+use crate::{
+    buffer::{array_buffer, uint8_array, BufferView, ByteBuf, Float32Vec, Float64Vec, Int32Vec},
+    date, duration,
+    helpers::MAX_SAFE_INTEGER,
+    value::{self, JsValue},
+};
 use deno_core::v8;
-use std::{collections::HashMap, hash::BuildHasher};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::OsString,
+    hash::BuildHasher,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
 
 /// The `IntoValue` trait is used to convert a Rust type into a v8 Value.
 pub trait IntoValue {
@@ -35,12 +49,114 @@ impl IntoValue for u32 {
     }
 }
 
-impl IntoValue for i64 {
-    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        v8::BigInt::new_from_i64(scope, self).into()
+/// Implements `IntoValue` for integers that always fit in an `i32`
+macro_rules! impl_into_small_integer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    v8::Integer::new(scope, i32::from(self)).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_small_integer!(i8, u8, i16, u16);
+
+/// Implements `IntoValue` for integers that may exceed `MAX_SAFE_INTEGER`: they become a Number when the value is
+/// exactly representable and a `BigInt` otherwise
+macro_rules! impl_into_integer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    match i64::try_from(self) {
+                        Ok(number) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&number) => self.into_number(scope),
+                        _ => self.into_big_int(scope),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_integer!(i64, u64, isize, usize, i128, u128);
+
+/// Integers that can be forced into either JS representation; used by the `#[v8(bigint)]` and `#[v8(number)]`
+/// field attributes
+pub trait IntoInteger {
+    /// Converts into a `BigInt`, whatever the value
+    fn into_big_int<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value>;
+
+    /// Converts into a Number, losing precision beyond `MAX_SAFE_INTEGER`
+    fn into_number<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value>;
+}
+
+/// Implements `IntoInteger`; `$parts` splits `$value` into its sign and magnitude
+macro_rules! impl_into_integer_repr {
+    ($($t:ty => |$value:ident| $parts:expr),*) => {
+        $(
+            impl IntoInteger for $t {
+                #[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+                fn into_big_int<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    let $value = self;
+                    let (negative, magnitude) = $parts;
+                    big_int(scope, negative, magnitude)
+                }
+
+                #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+                fn into_number<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    v8::Number::new(scope, self as f64).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_integer_repr! {
+    u8 => |value| (false, value as u128),
+    u16 => |value| (false, value as u128),
+    u32 => |value| (false, value as u128),
+    u64 => |value| (false, value as u128),
+    usize => |value| (false, value as u128),
+    u128 => |value| (false, value),
+    i8 => |value| (value < 0, value.unsigned_abs() as u128),
+    i16 => |value| (value < 0, value.unsigned_abs() as u128),
+    i32 => |value| (value < 0, value.unsigned_abs() as u128),
+    i64 => |value| (value < 0, value.unsigned_abs() as u128),
+    isize => |value| (value < 0, value.unsigned_abs() as u128),
+    i128 => |value| (value < 0, value.unsigned_abs())
+}
+
+impl<T> IntoInteger for Option<T>
+where
+    T: IntoInteger,
+{
+    fn into_big_int<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        match self {
+            Some(value) => value.into_big_int(scope),
+            None => v8::null(scope).into(),
+        }
+    }
+
+    fn into_number<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        match self {
+            Some(value) => value.into_number(scope),
+            None => v8::null(scope).into(),
+        }
     }
 }
 
+/// A `BigInt` from the sign and magnitude of an integer
+#[allow(clippy::cast_possible_truncation)]
+fn big_int<'a>(scope: &mut v8::HandleScope<'a>, negative: bool, magnitude: u128) -> v8::Local<'a, v8::Value> {
+    let words = [magnitude as u64, (magnitude >> 64) as u64];
+    v8::BigInt::new_from_words(scope, negative, &words)
+        .expect("two words are a valid BigInt")
+        .into()
+}
+
 impl IntoValue for f64 {
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
         v8::Number::new(scope, self).into()
@@ -53,9 +169,65 @@ impl IntoValue for f32 {
     }
 }
 
-impl IntoValue for String {
+/// Implements `IntoValue` for owned strings
+macro_rules! impl_into_string {
+    ($($t:ty),*) => {
+        $(
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    v8::String::new(scope, &self).unwrap_or(v8::String::empty(scope)).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_string!(String, Box<str>, Rc<str>, Arc<str>);
+
+impl IntoValue for char {
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        v8::String::new(scope, self.encode_utf8(&mut [0; 4]))
+            .unwrap_or(v8::String::empty(scope))
+            .into()
+    }
+}
+
+impl<T> IntoValue for Box<T>
+where
+    T: IntoValue,
+{
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        (*self).into_value(scope)
+    }
+}
+
+impl<T> IntoValue for Rc<T>
+where
+    T: IntoValue + Clone,
+{
+    /// Converts the value itself when this is the only reference to it, and a clone otherwise
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        Rc::unwrap_or_clone(self).into_value(scope)
+    }
+}
+
+impl<T> IntoValue for Arc<T>
+where
+    T: IntoValue + Clone,
+{
+    /// Converts the value itself when this is the only reference to it, and a clone otherwise
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        v8::String::new(scope, &self).unwrap_or(v8::String::empty(scope)).into()
+        Arc::unwrap_or_clone(self).into_value(scope)
+    }
+}
+
+impl<B> IntoValue for Cow<'_, B>
+where
+    B: ToOwned + ?Sized,
+    B::Owned: IntoValue,
+{
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        self.into_owned().into_value(scope)
     }
 }
 
@@ -76,19 +248,111 @@ where
     T: IntoValue,
 {
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        let l = i32::try_from(self.len()).unwrap_or(i32::MAX);
-        let array = v8::Array::new(scope, l);
+        array_from(scope, self)
+    }
+}
 
-        for (i, value) in self.into_iter().enumerate() {
-            let el: v8::Local<'_, v8::Value> = value.into_value(scope);
-            let idx = u32::try_from(i).unwrap_or(u32::MAX);
-            array.set_index(scope, idx, el);
-        }
+/// Implements `IntoValue` for collections converted by `$into`, into an Array or a Set
+macro_rules! impl_into_collection {
+    ($([$($param:ident),+] $t:ty where [$($bound:tt)+] => $into:ident;)*) => {
+        $(
+            impl<$($param),+> IntoValue for $t
+            where
+                $($bound)+
+            {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    $into(scope, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_collection! {
+    [T] VecDeque<T> where [T: IntoValue] => array_from;
+    [T] LinkedList<T> where [T: IntoValue] => array_from;
+    [T, S] HashSet<T, S> where [T: IntoValue] => set_from;
+    [T] BTreeSet<T> where [T: IntoValue] => set_from;
+}
+
+/// An Array holding the converted `elements`, in order
+fn array_from<'a, T: IntoValue>(
+    scope: &mut v8::HandleScope<'a>,
+    elements: impl IntoIterator<Item = T, IntoIter: ExactSizeIterator>,
+) -> v8::Local<'a, v8::Value> {
+    let elements = elements.into_iter();
+    let l = i32::try_from(elements.len()).unwrap_or(i32::MAX);
+    let array = v8::Array::new(scope, l);
+
+    for (i, value) in elements.enumerate() {
+        let el: v8::Local<'_, v8::Value> = value.into_value(scope);
+        let idx = u32::try_from(i).unwrap_or(u32::MAX);
+        array.set_index(scope, idx, el);
+    }
+
+    array.into()
+}
+
+/// A Set holding the converted `elements`, in order
+fn set_from<'a, T: IntoValue>(
+    scope: &mut v8::HandleScope<'a>,
+    elements: impl IntoIterator<Item = T>,
+) -> v8::Local<'a, v8::Value> {
+    let set = v8::Set::new(scope);
+
+    for value in elements {
+        let el = value.into_value(scope);
+        set.add(scope, el);
+    }
+
+    set.into()
+}
 
-        array.into()
+impl<T, const N: usize> IntoValue for [T; N]
+where
+    T: IntoValue,
+{
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        Vec::from(self).into_value(scope)
     }
 }
 
+/// Implements `IntoValue` for tuples, converted into an Array with one element per field
+macro_rules! impl_into_tuple {
+    ($($len:literal => ($($index:tt $name:ident),+)),*) => {
+        $(
+            impl<$($name),+> IntoValue for ($($name,)+)
+            where
+                $($name: IntoValue),+
+            {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    let array = v8::Array::new(scope, $len);
+                    $(
+                        let element = self.$index.into_value(scope);
+                        array.set_index(scope, $index, element);
+                    )+
+                    array.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_tuple! {
+    1 => (0 T0),
+    2 => (0 T0, 1 T1),
+    3 => (0 T0, 1 T1, 2 T2),
+    4 => (0 T0, 1 T1, 2 T2, 3 T3),
+    5 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4),
+    6 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5),
+    7 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6),
+    8 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7),
+    9 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8),
+    10 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9),
+    11 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10),
+    12 => (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11)
+}
+
 impl<K, T, S> IntoValue for HashMap<K, T, S>
 where
     K: IntoValue,
@@ -96,15 +360,7 @@ where
     S: BuildHasher,
 {
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        let object = v8::Map::new(scope);
-
-        for (key, value) in self {
-            let js_key = key.into_value(scope);
-            let js_val = value.into_value(scope);
-            object.set(scope, js_key, js_val);
-        }
-
-        object.into()
+        map_from(scope, self)
     }
 }
 
@@ -133,15 +389,132 @@ where
     S: BuildHasher,
 {
     fn into_object<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        let object = v8::Object::new(scope);
+        object_from(scope, self)
+    }
+}
 
-        for (key, value) in self {
-            let js_key = key.into_value(scope);
-            let js_val = value.into_value(scope);
-            object.set(scope, js_key, js_val);
+impl<T> IntoObject for Option<T>
+where
+    T: IntoObject,
+{
+    fn into_object<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        match self {
+            Some(value) => value.into_object(scope),
+            None => v8::null(scope).into(),
         }
+    }
+}
 
-        object.into()
+impl<K, T> IntoValue for BTreeMap<K, T>
+where
+    K: IntoValue,
+    T: IntoValue,
+{
+    /// A `Map` with the entries in key order
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        map_from(scope, self)
+    }
+}
+
+impl<K, T> IntoObject for BTreeMap<K, T>
+where
+    K: IntoValue,
+    T: IntoValue,
+{
+    fn into_object<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        object_from(scope, self)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, T, S> IntoValue for indexmap::IndexMap<K, T, S>
+where
+    K: IntoValue,
+    T: IntoValue,
+{
+    /// A `Map` with the entries in insertion order
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        map_from(scope, self)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, T, S> IntoObject for indexmap::IndexMap<K, T, S>
+where
+    K: IntoValue,
+    T: IntoValue,
+{
+    fn into_object<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        object_from(scope, self)
+    }
+}
+
+/// A `Map` holding the converted `entries`, in order
+fn map_from<'a, K: IntoValue, T: IntoValue>(
+    scope: &mut v8::HandleScope<'a>,
+    entries: impl IntoIterator<Item = (K, T)>,
+) -> v8::Local<'a, v8::Value> {
+    let object = v8::Map::new(scope);
+
+    for (key, value) in entries {
+        let js_key = key.into_value(scope);
+        let js_val = value.into_value(scope);
+        object.set(scope, js_key, js_val);
+    }
+
+    object.into()
+}
+
+/// An Object with a property for each of the converted `entries`
+fn object_from<'a, K: IntoValue, T: IntoValue>(
+    scope: &mut v8::HandleScope<'a>,
+    entries: impl IntoIterator<Item = (K, T)>,
+) -> v8::Local<'a, v8::Value> {
+    let object = v8::Object::new(scope);
+
+    for (key, value) in entries {
+        let js_key = key.into_value(scope);
+        let js_val = value.into_value(scope);
+        object.set(scope, js_key, js_val);
+    }
+
+    object.into()
+}
+
+impl IntoValue for ByteBuf {
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        uint8_array(scope, self.0)
+    }
+}
+
+impl IntoValue for BufferView<'_> {
+    /// A `Uint8Array` over the same memory, which is never shared
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        let (store, offset, length) = self.parts();
+        let buffer = v8::ArrayBuffer::with_backing_store(scope, store);
+        v8::Uint8Array::new(scope, buffer, offset, length).map_or_else(|| v8::undefined(scope).into(), Into::into)
+    }
+}
+
+/// Implements `IntoValue` for the vectors backed by typed arrays
+macro_rules! impl_into_typed_vec {
+    ($($t:ident),*) => {
+        $(
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    self.into_typed_array(scope)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_typed_vec!(Float32Vec, Float64Vec, Int32Vec);
+
+#[cfg(feature = "bytes")]
+impl IntoValue for bytes::Bytes {
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        uint8_array(scope, self.into())
     }
 }
 
@@ -152,35 +525,278 @@ impl IntoValue for serde_json::Value {
     }
 }
 
-#[cfg(feature = "chrono")]
-impl IntoValue for chrono::DateTime<chrono::FixedOffset> {
+/// Implements `IntoValue` for types converted into their string representation
+macro_rules! impl_into_display {
+    ($($(#[$attr:meta])* $t:ty),*) => {
+        $(
+            $(#[$attr])*
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    self.to_string().into_value(scope)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_display! {
+    IpAddr,
+    SocketAddr,
+    #[cfg(feature = "uuid")]
+    uuid::Uuid,
+    #[cfg(feature = "url")]
+    url::Url,
+    #[cfg(feature = "rust_decimal")]
+    rust_decimal::Decimal
+}
+
+impl IntoValue for PathBuf {
+    /// The path as a string, with invalid unicode replaced by `U+FFFD`
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        self.to_string_lossy().into_owned().into_value(scope)
+    }
+}
+
+impl IntoValue for OsString {
+    /// The string, with invalid unicode replaced by `U+FFFD`
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        let date_str = self.format("%+").to_string();
-        v8::String::new(scope, &date_str)
-            .unwrap_or(v8::String::empty(scope))
-            .into()
+        self.to_string_lossy().into_owned().into_value(scope)
     }
 }
 
-#[cfg(feature = "chrono")]
-impl IntoValue for chrono::NaiveDateTime {
+/// Implements `IntoValue` for dates and times, converted into a `Date`
+macro_rules! impl_into_date {
+    ($($(#[$attr:meta])* $t:ty),*) => {
+        $(
+            $(#[$attr])*
+            impl IntoValue for $t {
+                fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+                    date::into_value(self, scope)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_date! {
+    std::time::SystemTime,
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::Utc>,
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::FixedOffset>,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate,
+    #[cfg(feature = "chrono")]
+    chrono::NaiveTime,
+    #[cfg(feature = "time")]
+    time::OffsetDateTime,
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime,
+    #[cfg(feature = "time")]
+    time::Date
+}
+
+impl IntoValue for Duration {
+    /// The Number of milliseconds
     fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
-        let date_str = self.format("%+").to_string();
-        v8::String::new(scope, &date_str)
-            .unwrap_or(v8::String::empty(scope))
-            .into()
+        duration::into_value(self, scope)
+    }
+}
+
+impl IntoValue for JsValue {
+    fn into_value<'a>(self, scope: &mut v8::HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+        match self {
+            Self::Undefined => v8::undefined(scope).into(),
+            Self::Null => v8::null(scope).into(),
+            Self::Bool(value) => value.into_value(scope),
+            Self::Number(value) => value.into_value(scope),
+            Self::BigInt { negative, words } => v8::BigInt::new_from_words(scope, negative, &words)
+                .map_or_else(|| v8::undefined(scope).into(), Into::into),
+            Self::String(value) => value.into_value(scope),
+            Self::Symbol(description) => value::symbol(scope, description.as_deref()),
+            Self::Date(millis) => v8::Date::new(scope, millis).map_or_else(|| v8::undefined(scope).into(), Into::into),
+            Self::RegExp { source, flags } => value::reg_exp(scope, &source, &flags),
+            Self::Error { name, message } => value::error(scope, &name, &message),
+            Self::ArrayBuffer(bytes) => array_buffer(scope, bytes).into(),
+            Self::TypedArray { kind, bytes } => kind.view(scope, bytes),
+            Self::Array(elements) => array_from(scope, elements),
+            Self::Map(entries) => map_from(scope, entries),
+            Self::Set(elements) => set_from(scope, elements),
+            Self::Object(properties) => value::object(scope, properties),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
+        errors::Error,
         into::{IntoObject, IntoValue},
-        setup, TryFromValue,
+        setup, ConversionMode, TryFromValue,
     };
     use deno_core::v8;
     use deno_core::v8::{ContextOptions, CreateParams};
-    use std::collections::HashMap;
+    use std::{
+        borrow::Cow,
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+        rc::Rc,
+        sync::Arc,
+    };
+    use v8_derive_macros::{FromValue, IntoValue};
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Point(i32, i32),
+        Rectangle { width: i32, height: i32 },
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(rename_all = "camelCase")]
+    struct Renamed {
+        user_id: i32,
+        #[v8(rename = "mail")]
+        email: String,
+    }
+
+    #[allow(clippy::ref_option)]
+    fn is_none(value: &Option<String>) -> bool {
+        value.is_none()
+    }
+
+    struct Handle;
+
+    #[derive(FromValue, IntoValue)]
+    struct WithSkippedFields {
+        name: String,
+        #[v8(skip)]
+        cache: std::marker::PhantomData<Handle>,
+        #[v8(skip_into, default)]
+        secret: String,
+        #[v8(skip_from)]
+        computed: i32,
+        #[v8(skip_into_if = "is_none")]
+        nickname: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Command {
+        StartJob {
+            job_id: i32,
+        },
+        #[v8(rename = "halt", alias = "stop")]
+        StopAll,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(tag = "type")]
+    enum Event {
+        Close,
+        Click { x: i32, y: i32 },
+        Focus(Renamed),
+        Text(String),
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(tag = "t", content = "c")]
+    enum Adjacent {
+        Empty,
+        Number(i32),
+        Pair(i32, i32),
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(untagged)]
+    enum Untagged {
+        Nothing,
+        Click { x: i32, y: i32 },
+        Text(String),
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct UserId(String);
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Position(i32, i32);
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Marker;
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(transparent)]
+    struct Wrapper {
+        inner: i32,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Counters {
+        count: i64,
+        #[v8(bigint)]
+        id: u64,
+        #[v8(number)]
+        total: i128,
+        #[v8(bigint)]
+        parent: Option<i64>,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Segment {
+        ends: [(i32, i32); 2],
+        label: (String, u8, bool),
+    }
+
+    #[derive(Debug, Clone, PartialEq, FromValue, IntoValue)]
+    struct Node {
+        name: Box<str>,
+        next: Option<Box<Node>>,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Shared {
+        root: Arc<Node>,
+        label: Cow<'static, str>,
+        separator: char,
+        tags: Rc<[String; 2]>,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Listener {
+        root: std::path::PathBuf,
+        name: std::ffi::OsString,
+        host: std::net::IpAddr,
+        bind: std::net::SocketAddr,
+    }
+
+    /// Nothing is imported here and `v8` names an unrelated module; the derives must not depend on either
+    mod hygiene {
+        #[allow(dead_code)]
+        mod v8 {}
+
+        #[derive(Debug, PartialEq, v8_derive_macros::FromValue, v8_derive_macros::IntoValue)]
+        #[v8(crate = "crate")]
+        pub struct Isolated {
+            pub name: String,
+            pub tags: Vec<String>,
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: u32,
+    }
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    #[v8(bound = "")]
+    struct Tagged<'a, T> {
+        name: String,
+        #[v8(skip)]
+        marker: std::marker::PhantomData<&'a T>,
+    }
 
     #[test]
     #[allow(clippy::cast_possible_wrap)]
@@ -306,6 +922,553 @@ mod tests {
         assert_eq!(map.get("one"), Some(&"1".to_string()));
     }
 
+    #[test]
+    fn can_round_trip_renamed_fields_and_variants() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let renamed = Renamed {
+            user_id: 7,
+            email: "john@example.com".to_string(),
+        };
+        let value = renamed.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("userId"), Some(&"7".to_string()));
+        assert_eq!(map.get("mail"), Some(&"john@example.com".to_string()));
+        assert_eq!(
+            Renamed::try_from_value(&value, scope).unwrap(),
+            Renamed {
+                user_id: 7,
+                email: "john@example.com".to_string(),
+            }
+        );
+
+        let value = Command::StartJob { job_id: 1 }.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("kind"), Some(&"START_JOB".to_string()));
+        assert_eq!(map.get("job_id"), Some(&"1".to_string()));
+
+        let value = Command::StopAll.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("kind"), Some(&"halt".to_string()));
+
+        // the alias is accepted when reading
+        let object = v8::Object::new(scope);
+        let js_key = v8::String::new(scope, "kind").unwrap().into();
+        let js_val = v8::String::new(scope, "stop").unwrap().into();
+        object.set(scope, js_key, js_val);
+        assert_eq!(
+            Command::try_from_value(&object.into(), scope).unwrap(),
+            Command::StopAll
+        );
+    }
+
+    #[test]
+    fn can_skip_fields() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let object = WithSkippedFields {
+            name: "John".to_string(),
+            cache: std::marker::PhantomData,
+            secret: "hunter2".to_string(),
+            computed: 42,
+            nickname: None,
+        };
+        let value = object.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("name"), Some(&"John".to_string()));
+        assert_eq!(map.get("computed"), Some(&"42".to_string()));
+
+        let object = WithSkippedFields::try_from_value(&value, scope).expect("failed to deserialize");
+        assert_eq!(object.name, "John");
+        assert_eq!(object.secret, "");
+        assert_eq!(object.computed, 0);
+        assert_eq!(object.nickname, None);
+    }
+
+    #[test]
+    fn can_round_trip_an_externally_tagged_enum() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = Shape::Empty.into_value(scope);
+        assert!(value.is_string());
+        assert_eq!(Shape::try_from_value(&value, scope).unwrap(), Shape::Empty);
+
+        let value = Shape::Rectangle { width: 2, height: 3 }.into_value(scope);
+        let map = HashMap::<String, HashMap<String, i32>>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map["Rectangle"]["width"], 2);
+
+        for shape in [
+            Shape::Circle(1.5),
+            Shape::Point(1, 2),
+            Shape::Rectangle { width: 2, height: 3 },
+        ] {
+            let debug = format!("{shape:?}");
+            let value = shape.into_value(scope);
+            assert_eq!(format!("{:?}", Shape::try_from_value(&value, scope).unwrap()), debug);
+        }
+    }
+
+    #[test]
+    fn can_round_trip_an_internally_tagged_enum() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = Event::Click { x: 1, y: 2 }.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("type"), Some(&"Click".to_string()));
+        assert_eq!(map.get("x"), Some(&"1".to_string()));
+        assert_eq!(
+            Event::try_from_value(&value, scope).unwrap(),
+            Event::Click { x: 1, y: 2 }
+        );
+
+        let value = Event::Close.into_value(scope);
+        assert_eq!(Event::try_from_value(&value, scope).unwrap(), Event::Close);
+
+        let focus = Event::Focus(Renamed {
+            user_id: 1,
+            email: "a@b.c".to_string(),
+        });
+        let value = focus.into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("type"), Some(&"Focus".to_string()));
+        assert_eq!(map.get("userId"), Some(&"1".to_string()));
+
+        // a string cannot carry the tag
+        let scope = &mut v8::TryCatch::new(scope);
+        let value = Event::Text("Hello".to_string()).into_value(scope);
+        assert!(value.is_undefined());
+        assert!(scope.has_caught());
+    }
+
+    #[test]
+    fn can_round_trip_an_adjacently_tagged_enum() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = Adjacent::Number(42).into_value(scope);
+        let map = HashMap::<String, String>::try_from_value(&value, scope).expect("Expected a map");
+        assert_eq!(map.get("t"), Some(&"Number".to_string()));
+        assert_eq!(map.get("c"), Some(&"42".to_string()));
+
+        for adjacent in [Adjacent::Empty, Adjacent::Number(42), Adjacent::Pair(1, 2)] {
+            let debug = format!("{adjacent:?}");
+            let value = adjacent.into_value(scope);
+            assert_eq!(format!("{:?}", Adjacent::try_from_value(&value, scope).unwrap()), debug);
+        }
+    }
+
+    #[test]
+    fn can_round_trip_an_untagged_enum() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = Untagged::Text("Hello".to_string()).into_value(scope);
+        assert!(value.is_string());
+        assert_eq!(
+            Untagged::try_from_value(&value, scope).unwrap(),
+            Untagged::Text("Hello".to_string())
+        );
+
+        let value = Untagged::Click { x: 1, y: 2 }.into_value(scope);
+        assert_eq!(
+            Untagged::try_from_value(&value, scope).unwrap(),
+            Untagged::Click { x: 1, y: 2 }
+        );
+
+        let value = Untagged::Nothing.into_value(scope);
+        assert!(value.is_null());
+        assert_eq!(Untagged::try_from_value(&value, scope).unwrap(), Untagged::Nothing);
+    }
+
+    #[test]
+    fn can_round_trip_tuple_and_unit_structs() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = UserId("abc".to_string()).into_value(scope);
+        assert!(value.is_string());
+        assert_eq!(
+            UserId::try_from_value(&value, scope).unwrap(),
+            UserId("abc".to_string())
+        );
+
+        let value = Position(1, 2).into_value(scope);
+        assert!(value.is_array());
+        assert_eq!(Position::try_from_value(&value, scope).unwrap(), Position(1, 2));
+
+        let value = Marker.into_value(scope);
+        assert!(value.is_null());
+        assert_eq!(Marker::try_from_value(&value, scope).unwrap(), Marker);
+
+        let value = Wrapper { inner: 42 }.into_value(scope);
+        assert!(value.is_number());
+        assert_eq!(Wrapper::try_from_value(&value, scope).unwrap(), Wrapper { inner: 42 });
+    }
+
+    #[test]
+    fn can_round_trip_generic_structs() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let page = Page {
+            items: vec![UserId("a".to_string()), UserId("b".to_string())],
+            total: 2,
+        };
+        let value = page.into_value(scope);
+        assert_eq!(
+            Page::<UserId>::try_from_value(&value, scope).unwrap(),
+            Page {
+                items: vec![UserId("a".to_string()), UserId("b".to_string())],
+                total: 2,
+            }
+        );
+
+        // `Handle` implements neither trait; `bound = ""` drops the inferred `T` bounds
+        let tagged = Tagged::<Handle> {
+            name: "handle".to_string(),
+            marker: std::marker::PhantomData,
+        };
+        let value = tagged.into_value(scope);
+        assert_eq!(Tagged::<Handle>::try_from_value(&value, scope).unwrap().name, "handle");
+    }
+
+    #[test]
+    fn can_round_trip_without_imports_using_a_custom_crate_path() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let isolated = hygiene::Isolated {
+            name: "isolated".to_string(),
+            tags: vec!["a".to_string()],
+        };
+        let value = isolated.into_value(scope);
+        assert_eq!(
+            hygiene::Isolated::try_from_value(&value, scope).unwrap(),
+            hygiene::Isolated {
+                name: "isolated".to_string(),
+                tags: vec!["a".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn can_round_trip_the_integer_family() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let value = u8::MAX.into_value(scope);
+        assert_eq!(u8::try_from_value(&value, scope).unwrap(), u8::MAX);
+        let value = i16::MIN.into_value(scope);
+        assert_eq!(i16::try_from_value(&value, scope).unwrap(), i16::MIN);
+
+        // safe integers become Numbers
+        let value = 9_007_199_254_740_991_u64.into_value(scope);
+        assert!(value.is_number());
+        assert_eq!(u64::try_from_value(&value, scope).unwrap(), 9_007_199_254_740_991);
+
+        // larger ones become BigInts
+        let value = u64::MAX.into_value(scope);
+        assert!(value.is_big_int());
+        assert_eq!(u64::try_from_value(&value, scope).unwrap(), u64::MAX);
+        let value = i128::MIN.into_value(scope);
+        assert!(value.is_big_int());
+        assert_eq!(i128::try_from_value(&value, scope).unwrap(), i128::MIN);
+        let value = u128::MAX.into_value(scope);
+        assert_eq!(u128::try_from_value(&value, scope).unwrap(), u128::MAX);
+        let value = isize::MIN.into_value(scope);
+        assert_eq!(isize::try_from_value(&value, scope).unwrap(), isize::MIN);
+
+        // out of range values are refused
+        let value = 256_u16.into_value(scope);
+        u8::try_from_value(&value, scope).expect_err("256 is not a u8");
+    }
+
+    #[test]
+    fn can_choose_the_representation_of_integer_fields() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        // i64 accepts plain Numbers
+        let object = v8::Object::new(scope);
+        for (key, value) in [("count", 5), ("id", 7), ("total", 9)] {
+            let js_key = v8::String::new(scope, key).unwrap().into();
+            let js_val = v8::Number::new(scope, f64::from(value)).into();
+            object.set(scope, js_key, js_val);
+        }
+        let counters = Counters::try_from_value(&object.into(), scope).unwrap();
+        assert_eq!(
+            counters,
+            Counters {
+                count: 5,
+                id: 7,
+                total: 9,
+                parent: None
+            }
+        );
+
+        let counters = Counters {
+            parent: Some(1),
+            ..counters
+        };
+        let value = counters.into_value(scope);
+        let object = value.to_object(scope).unwrap();
+        let mut property = |name: &str| {
+            let js_key = v8::String::new(scope, name).unwrap().into();
+            object.get(scope, js_key).unwrap()
+        };
+        assert!(property("count").is_number());
+        assert!(property("id").is_big_int());
+        assert!(property("total").is_number());
+        assert!(property("parent").is_big_int());
+        assert_eq!(
+            Counters::try_from_value(&value, scope).unwrap(),
+            Counters {
+                count: 5,
+                id: 7,
+                total: 9,
+                parent: Some(1)
+            }
+        );
+    }
+
+    #[test]
+    fn can_round_trip_tuples_and_arrays() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let segment = Segment {
+            ends: [(0, 0), (3, -4)],
+            label: ("diagonal".to_string(), 5, true),
+        };
+        let value = segment.into_value(scope);
+        assert_eq!(
+            Segment::try_from_value(&value, scope).unwrap(),
+            Segment {
+                ends: [(0, 0), (3, -4)],
+                label: ("diagonal".to_string(), 5, true),
+            }
+        );
+
+        let value = (1, 2, 3).into_value(scope);
+        assert_eq!(<[u8; 3]>::try_from_value(&value, scope).unwrap(), [1, 2, 3]);
+        let error = <(u8, u8)>::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::LengthMismatch { expected: 2, actual: 3 }));
+        assert_eq!(
+            error.to_string(),
+            "Conversion failed; Expected an Array of 2 elements, found 3"
+        );
+        let error = <[u8; 4]>::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::LengthMismatch { expected: 4, actual: 3 }));
+    }
+
+    #[test]
+    fn can_round_trip_collections() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let queue = VecDeque::from([3, 1, 2]);
+        let value = queue.clone().into_value(scope);
+        assert!(value.is_array());
+        assert_eq!(VecDeque::<i32>::try_from_value(&value, scope).unwrap(), queue);
+
+        let scores = BTreeMap::from([("bob".to_string(), 2), ("alice".to_string(), 1)]);
+        let value = scores.clone().into_value(scope);
+        assert!(value.is_map());
+        assert_eq!(BTreeMap::<String, i32>::try_from_value(&value, scope).unwrap(), scores);
+        let value = scores.clone().into_object(scope);
+        assert!(value.is_object());
+        assert_eq!(BTreeMap::<String, i32>::try_from_value(&value, scope).unwrap(), scores);
+
+        let tags = BTreeSet::from(["a".to_string(), "b".to_string()]);
+        let value = tags.clone().into_value(scope);
+        assert!(value.is_set());
+        assert_eq!(BTreeSet::<String>::try_from_value(&value, scope).unwrap(), tags);
+        let tags: HashSet<String> = tags.into_iter().collect();
+        assert_eq!(HashSet::<String>::try_from_value(&value, scope).unwrap(), tags);
+
+        // duplicates in an Array collapse; strict mode only accepts a Set
+        let value = vec![1, 2, 1].into_value(scope);
+        assert_eq!(
+            BTreeSet::<i32>::try_from_value(&value, scope).unwrap(),
+            BTreeSet::from([1, 2])
+        );
+        let error = BTreeSet::<i32>::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+        assert!(matches!(error, Error::ExpectedSet));
+    }
+
+    #[test]
+    fn can_round_trip_smart_pointers() {
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let leaf = Node {
+            name: "leaf".into(),
+            next: None,
+        };
+        let root = Arc::new(Node {
+            name: "root".into(),
+            next: Some(Box::new(leaf)),
+        });
+        let shared = Shared {
+            root: Arc::clone(&root),
+            label: Cow::Borrowed("tree"),
+            separator: '/',
+            tags: Rc::new(["a".to_string(), "b".to_string()]),
+        };
+        let value = shared.into_value(scope);
+        assert_eq!(
+            Shared::try_from_value(&value, scope).unwrap(),
+            Shared {
+                root,
+                label: Cow::Owned("tree".to_string()),
+                separator: '/',
+                tags: Rc::new(["a".to_string(), "b".to_string()]),
+            }
+        );
+
+        let value = "ab".to_string().into_value(scope);
+        let error = char::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::ExpectedChar));
+        let value = 7.into_value(scope);
+        assert_eq!(char::try_from_value(&value, scope).unwrap(), '7');
+        let error = char::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+        assert!(matches!(error, Error::ExpectedString));
+    }
+
+    #[test]
+    fn can_round_trip_paths_and_addresses() {
+        use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let listener = Listener {
+            root: "/srv/www".into(),
+            name: "web".into(),
+            host: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            bind: "127.0.0.1:8080".parse().unwrap(),
+        };
+        let value = listener.into_value(scope);
+        let object = value.to_object(scope).unwrap();
+        let key = v8::String::new(scope, "host").unwrap();
+        let host = object.get(scope, key.into()).unwrap();
+        assert_eq!(host.to_rust_string_lossy(scope), "::1");
+        let key = v8::String::new(scope, "bind").unwrap();
+        let bind = object.get(scope, key.into()).unwrap();
+        assert_eq!(bind.to_rust_string_lossy(scope), "127.0.0.1:8080");
+        assert_eq!(
+            Listener::try_from_value(&value, scope).unwrap(),
+            Listener {
+                root: "/srv/www".into(),
+                name: "web".into(),
+                host: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                bind: "127.0.0.1:8080".parse().unwrap(),
+            }
+        );
+
+        let value = v8::String::new(scope, "localhost").unwrap().into();
+        let error = IpAddr::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::InvalidIpAddr(_)));
+        let error = SocketAddr::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::InvalidSocketAddr(_)));
+        let value = v8::Number::new(scope, 1.0).into();
+        let error = IpAddr::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+        assert!(matches!(error, Error::ExpectedString));
+    }
+
+    #[cfg(all(feature = "uuid", feature = "url", feature = "rust_decimal"))]
+    #[test]
+    fn can_round_trip_uuids_urls_and_decimals() {
+        use rust_decimal::Decimal;
+
+        setup::setup_test();
+        let isolate = &mut v8::Isolate::new(CreateParams::default());
+        let scope = &mut v8::HandleScope::new(isolate);
+        let context = v8::Context::new(scope, ContextOptions::default());
+        let scope = &mut v8::ContextScope::new(scope, context);
+
+        let id = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let value = id.into_value(scope);
+        assert_eq!(
+            value.to_rust_string_lossy(scope),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(uuid::Uuid::try_from_value(&value, scope).unwrap(), id);
+
+        let url = url::Url::parse("https://example.com/a?b=c").unwrap();
+        let value = url.clone().into_value(scope);
+        assert_eq!(url::Url::try_from_value(&value, scope).unwrap(), url);
+        let value = v8::String::new(scope, "not a url").unwrap().into();
+        let error = url::Url::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::InvalidUrl(_)));
+
+        let price = Decimal::new(1999, 2);
+        let value = price.into_value(scope);
+        assert_eq!(value.to_rust_string_lossy(scope), "19.99");
+        assert_eq!(Decimal::try_from_value(&value, scope).unwrap(), price);
+
+        // Numbers only outside strict mode, in whatever notation JS prints them
+        let value = v8::Number::new(scope, 19.99).into();
+        assert_eq!(Decimal::try_from_value(&value, scope).unwrap(), price);
+        let value = v8::Number::new(scope, 1e-7).into();
+        assert_eq!(Decimal::try_from_value(&value, scope).unwrap(), Decimal::new(1, 7));
+        let error = Decimal::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+        assert!(matches!(error, Error::ExpectedString));
+        let value = v8::String::new(scope, "lots").unwrap().into();
+        let error = Decimal::try_from_value(&value, scope).unwrap_err();
+        assert!(matches!(error, Error::InvalidDecimal(_)));
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn can_convert_json_into_a_js_object() {
@@ -0,0 +1,208 @@
+//! Map keys: converted from and into the keys of a JS `Map`, or the property names of an object.
+
+use crate::{
+    errors::{Error, Result},
+    from::{ConversionMode, TryFromValue},
+    helpers::try_as_char,
+    value::JsValue,
+};
+use deno_core::v8;
+use std::{num::IntErrorKind, rc::Rc, sync::Arc};
+
+/// A type that can key a `HashMap`, `BTreeMap` or `IndexMap` converted from JS; converting a map into JS only needs
+/// its keys to implement `IntoValue`.
+///
+/// The keys of a `Map` are converted as they are, in the mode of the map. Property names of an object arrive as
+/// Numbers when they are array indices, like `"7"`, and as Strings otherwise, and are always converted leniently.
+/// By default a key converts like any other value, so derived
+/// enums and newtypes only need an empty `impl MapKey for Color {}`.
+pub trait MapKey: TryFromValue + Sized {
+    /// Converts the key of a `Map` entry, or a property name
+    ///
+    /// # Errors
+    /// The error of the conversion when the key is not of this type.
+    fn try_from_key(
+        key: v8::Local<'_, v8::Value>,
+        scope: &mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self> {
+        Self::try_from_value_with(&key, scope, mode)
+    }
+}
+
+impl MapKey for String {
+    /// Any key, as the JS string it converts to; only a String when converting strictly
+    fn try_from_key(
+        key: v8::Local<'_, v8::Value>,
+        scope: &mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self> {
+        if mode.is_strict() && !key.is_string() {
+            return Err(Error::ExpectedString);
+        }
+        Ok(key.to_rust_string_lossy(scope))
+    }
+}
+
+/// Implements `MapKey` for shared and boxed strings, read like `String` keys
+macro_rules! impl_map_key_string {
+    ($($t:ty),*) => {
+        $(
+            impl MapKey for $t {
+                fn try_from_key(
+                    key: v8::Local<'_, v8::Value>,
+                    scope: &mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> Result<Self> {
+                    String::try_from_key(key, scope, mode).map(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+impl_map_key_string!(Box<str>, Rc<str>, Arc<str>);
+
+impl MapKey for char {
+    /// Any key that is a single character as a JS string, including the property names `"0"` to `"9"`; only a
+    /// String when converting strictly
+    fn try_from_key(
+        key: v8::Local<'_, v8::Value>,
+        scope: &mut v8::HandleScope<'_, v8::Context>,
+        mode: ConversionMode,
+    ) -> Result<Self> {
+        if mode.is_strict() && !key.is_string() {
+            return Err(Error::ExpectedString);
+        }
+        try_as_char(&key, scope)
+    }
+}
+
+impl MapKey for bool {}
+
+impl MapKey for JsValue {}
+
+/// Implements `MapKey` for integers, which are also parsed from property names such as `"-1"`
+macro_rules! impl_map_key_integer {
+    ($($t:ty),*) => {
+        $(
+            impl MapKey for $t {
+                fn try_from_key(
+                    key: v8::Local<'_, v8::Value>,
+                    scope: &mut v8::HandleScope<'_, v8::Context>,
+                    mode: ConversionMode,
+                ) -> Result<Self> {
+                    if !key.is_string() {
+                        return Self::try_from_value_with(&key, scope, mode);
+                    }
+
+                    let name = key.to_rust_string_lossy(scope);
+                    name.parse().map_err(|error: std::num::ParseIntError| match error.kind() {
+                        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Error::OutOfRange(name),
+                        _ => Error::ExpectedInteger,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_map_key_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::MapKey;
+    use crate::{
+        errors::Error,
+        into::{IntoObject, IntoValue},
+        setup, ConversionMode, TryFromValue,
+    };
+    use deno_core::v8;
+    use std::collections::{BTreeMap, HashMap};
+    use v8_derive_macros::{FromValue, IntoValue};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, FromValue, IntoValue)]
+    enum Channel {
+        Stable,
+        Beta,
+    }
+
+    impl MapKey for Channel {}
+
+    #[derive(Debug, PartialEq, FromValue, IntoValue)]
+    struct Releases {
+        #[v8(as_object)]
+        latest: BTreeMap<Channel, u32>,
+        #[v8(as_map)]
+        by_build: HashMap<u64, String>,
+    }
+
+    /// A key that is never read back, so it does not implement `MapKey`
+    #[derive(PartialEq, Eq, PartialOrd, Ord, IntoValue)]
+    struct Label(String);
+
+    #[test]
+    fn can_convert_maps_with_other_keys() {
+        setup::with_scope(|scope| {
+            let releases = Releases {
+                latest: BTreeMap::from([(Channel::Stable, 41), (Channel::Beta, 42)]),
+                by_build: HashMap::from([(4102, "beta".to_string())]),
+            };
+            let value = releases.into_value(scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "latest").unwrap();
+            let latest = object.get(scope, key.into()).unwrap();
+            assert!(latest.is_object() && !latest.is_map());
+            let key = v8::String::new(scope, "by_build").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_map());
+            assert_eq!(
+                Releases::try_from_value(&value, scope).unwrap(),
+                Releases {
+                    latest: BTreeMap::from([(Channel::Stable, 41), (Channel::Beta, 42)]),
+                    by_build: HashMap::from([(4102, "beta".to_string())]),
+                }
+            );
+
+            // integer property names, including ones that are not array indices
+            let offsets = HashMap::from([(-1, 1), (7, 2)]);
+            let value = offsets.clone().into_object(scope);
+            assert_eq!(HashMap::<i32, i32>::try_from_value(&value, scope).unwrap(), offsets);
+            let value = offsets.into_object(scope);
+            let error = HashMap::<u8, i32>::try_from_value(&value, scope).unwrap_err();
+            assert!(matches!(error, Error::AtPath { ref source, .. } if matches!(**source, Error::ExpectedInteger)));
+            let value = HashMap::from([(300, 1)]).into_object(scope);
+            let error = HashMap::<u8, i32>::try_from_value(&value, scope).unwrap_err();
+            assert_eq!(error.path().unwrap().to_string(), "[\"300\"]");
+        });
+    }
+
+    #[test]
+    fn can_convert_maps_with_keys_that_only_convert_into_js() {
+        setup::with_scope(|scope| {
+            let value = BTreeMap::from([(Label("a".to_string()), 1), (Label("b".to_string()), 2)]).into_value(scope);
+            let entries = BTreeMap::<String, i32>::try_from_value(&value, scope).unwrap();
+            assert_eq!(entries, BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)]));
+
+            let value = BTreeMap::from([(Label("c".to_string()), 3)]).into_object(scope);
+            assert!(!value.is_map());
+            let entries = BTreeMap::<String, i32>::try_from_value(&value, scope).unwrap();
+            assert_eq!(entries, BTreeMap::from([("c".to_string(), 3)]));
+        });
+    }
+
+    #[test]
+    fn only_reads_string_keys_of_a_map_in_strict_mode() {
+        setup::with_scope(|scope| {
+            let value = HashMap::from([(7, 1)]).into_value(scope);
+            let entries = HashMap::<String, i32>::try_from_value(&value, scope).unwrap();
+            assert_eq!(entries, HashMap::from([("7".to_string(), 1)]));
+            let error = HashMap::<String, i32>::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::AtPath { ref source, .. } if matches!(**source, Error::ExpectedString)));
+
+            // property names are strings, even the ones that arrive as Numbers
+            let value = HashMap::from([(7, 1)]).into_object(scope);
+            let entries = HashMap::<String, i32>::try_from_value_with(&value, scope, ConversionMode::Strict).unwrap();
+            assert_eq!(entries, HashMap::from([("7".to_string(), 1)]));
+        });
+    }
+}
@@ -1,9 +1,17 @@
 #![warn(clippy::pedantic)]
 
-pub use from::TryFromValue;
+extern crate self as v8_derive;
+
+pub use deno_core::v8;
+pub use from::{ConversionMode, TryFromValue};
 pub use helpers::*;
-pub use into::IntoValue;
+pub use into::{IntoInteger, IntoValue};
+pub use key::MapKey;
+pub use value::JsValue;
 
+pub mod buffer;
+pub mod date;
+pub mod duration;
 pub mod errors;
 pub mod from;
 
@@ -11,6 +19,12 @@ pub mod from;
 pub mod helpers;
 
 pub mod into;
+pub mod key;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+pub mod value;
 
 #[cfg(feature = "json")]
 mod json;
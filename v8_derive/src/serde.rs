@@ -0,0 +1,924 @@
+//! Serde support: types implementing `Serialize` and `Deserialize` converted into and from V8 values directly, in
+//! the representations used by the derives.
+
+use crate::{
+    buffer::{uint8_array, ByteBuf},
+    errors::{Error, Located, PathSegment, Result},
+    from::{ConversionMode, TryFromValue},
+    helpers::{entries, map_key_mode, MAX_SAFE_INTEGER},
+    into::IntoValue,
+};
+use ::serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor},
+    ser::{self, Serialize},
+};
+use deno_core::v8;
+use std::fmt::Display;
+
+/// Converts `value` into a V8 value
+///
+/// # Errors
+/// The errors raised by the `Serialize` implementation of `T`.
+pub fn to_v8<'s, T>(scope: &mut v8::HandleScope<'s>, value: &T) -> Result<v8::Local<'s, v8::Value>>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Serializer::new(scope))
+}
+
+/// Converts a V8 value into `T`, coercing values of another JS type like `TryFromValue::try_from_value` does
+///
+/// # Errors
+/// In case of conversion errors, with the path to the offending value.
+pub fn from_v8<T>(scope: &mut v8::HandleScope<'_, v8::Context>, value: v8::Local<'_, v8::Value>) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    from_v8_with(scope, value, ConversionMode::Lenient)
+}
+
+/// Converts a V8 value into `T` using the given conversion mode
+///
+/// # Errors
+/// In case of conversion errors, with the path to the offending value.
+pub fn from_v8_with<T>(
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    value: v8::Local<'_, v8::Value>,
+    mode: ConversionMode,
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(Deserializer::new(scope, value, mode))
+}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+/// Builds V8 values from any `Serialize` type.
+///
+/// Structs become Objects, maps become a `Map`, sequences and tuples become Arrays, bytes become a `Uint8Array` and
+/// `None` and unit become `null`. Enums are externally tagged, like the derives do by default.
+pub struct Serializer<'a, 's> {
+    scope: &'a mut v8::HandleScope<'s>,
+}
+
+impl<'a, 's> Serializer<'a, 's> {
+    pub fn new(scope: &'a mut v8::HandleScope<'s>) -> Self {
+        Self { scope }
+    }
+}
+
+/// Implements the `serialize_*` methods that convert with `IntoValue`
+macro_rules! serialize_into_value {
+    ($($method:ident($t:ty)),*) => {
+        $(
+            fn $method(self, value: $t) -> Result<Self::Ok> {
+                Ok(value.into_value(self.scope))
+            }
+        )*
+    };
+}
+
+impl<'a, 's> ser::Serializer for Serializer<'a, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+    type SerializeSeq = ArraySerializer<'a, 's>;
+    type SerializeTuple = ArraySerializer<'a, 's>;
+    type SerializeTupleStruct = ArraySerializer<'a, 's>;
+    type SerializeTupleVariant = VariantSerializer<ArraySerializer<'a, 's>>;
+    type SerializeMap = MapSerializer<'a, 's>;
+    type SerializeStruct = ObjectSerializer<'a, 's>;
+    type SerializeStructVariant = VariantSerializer<ObjectSerializer<'a, 's>>;
+
+    serialize_into_value!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char)
+    );
+
+    fn serialize_str(self, value: &str) -> Result<Self::Ok> {
+        Ok(value.to_string().into_value(self.scope))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok> {
+        Ok(uint8_array(self.scope, value.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok> {
+        Ok(v8::null(self.scope).into())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        Ok(v8::null(self.scope).into())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Self::Ok> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        let content = value.serialize(Serializer::new(self.scope))?;
+        Ok(tagged(self.scope, variant, content))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(ArraySerializer {
+            scope: self.scope,
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        let content = self.serialize_seq(Some(len))?;
+        Ok(VariantSerializer { variant, content })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        let map = v8::Map::new(self.scope);
+        Ok(MapSerializer {
+            scope: self.scope,
+            map,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        let object = v8::Object::new(self.scope);
+        Ok(ObjectSerializer {
+            scope: self.scope,
+            object,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        let content = self.serialize_struct(name, len)?;
+        Ok(VariantSerializer { variant, content })
+    }
+}
+
+/// `{ variant: content }`, an externally tagged enum variant
+fn tagged<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    variant: &str,
+    content: v8::Local<'s, v8::Value>,
+) -> v8::Local<'s, v8::Value> {
+    let object = v8::Object::new(scope);
+    if let Some(key) = v8::String::new(scope, variant) {
+        object.set(scope, key.into(), content);
+    }
+    object.into()
+}
+
+/// Serializes the elements of a sequence or tuple into an Array
+pub struct ArraySerializer<'a, 's> {
+    scope: &'a mut v8::HandleScope<'s>,
+    elements: Vec<v8::Local<'s, v8::Value>>,
+}
+
+impl<'s> ArraySerializer<'_, 's> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let element = value.serialize(Serializer::new(self.scope))?;
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn finish(self) -> v8::Local<'s, v8::Value> {
+        v8::Array::new_with_elements(self.scope, &self.elements).into()
+    }
+}
+
+impl<'s> ser::SerializeSeq for ArraySerializer<'_, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        Ok(self.finish())
+    }
+}
+
+impl<'s> ser::SerializeTuple for ArraySerializer<'_, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        Ok(self.finish())
+    }
+}
+
+impl<'s> ser::SerializeTupleStruct for ArraySerializer<'_, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        Ok(self.finish())
+    }
+}
+
+/// Serializes the entries of a map into a `Map`
+pub struct MapSerializer<'a, 's> {
+    scope: &'a mut v8::HandleScope<'s>,
+    map: v8::Local<'s, v8::Map>,
+    key: Option<v8::Local<'s, v8::Value>>,
+}
+
+impl<'s> ser::SerializeMap for MapSerializer<'_, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(Serializer::new(self.scope))?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::Custom("serialize_value called before serialize_key".to_string()))?;
+        let value = value.serialize(Serializer::new(self.scope))?;
+        self.map.set(self.scope, key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        Ok(self.map.into())
+    }
+}
+
+/// Serializes the fields of a struct into an Object
+pub struct ObjectSerializer<'a, 's> {
+    scope: &'a mut v8::HandleScope<'s>,
+    object: v8::Local<'s, v8::Object>,
+}
+
+impl ObjectSerializer<'_, '_> {
+    fn set<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<()> {
+        let value = value.serialize(Serializer::new(self.scope))?;
+        let key = v8::String::new(self.scope, name).ok_or_else(|| Error::InvalidField(name.to_string()))?;
+        self.object.set(self.scope, key.into(), value);
+        Ok(())
+    }
+}
+
+impl<'s> ser::SerializeStruct for ObjectSerializer<'_, 's> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<()> {
+        self.set(name, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        Ok(self.object.into())
+    }
+}
+
+/// Serializes the content of a tuple or struct variant, wrapped in `{ variant: content }` when done
+pub struct VariantSerializer<S> {
+    variant: &'static str,
+    content: S,
+}
+
+impl<'s> ser::SerializeTupleVariant for VariantSerializer<ArraySerializer<'_, 's>> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.content.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        let Self { variant, content } = self;
+        let scope = &mut *content.scope;
+        let content = v8::Array::new_with_elements(scope, &content.elements).into();
+        Ok(tagged(scope, variant, content))
+    }
+}
+
+impl<'s> ser::SerializeStructVariant for VariantSerializer<ObjectSerializer<'_, 's>> {
+    type Ok = v8::Local<'s, v8::Value>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<()> {
+        self.content.set(name, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        let Self { variant, content } = self;
+        Ok(tagged(content.scope, variant, content.object.into()))
+    }
+}
+
+/// Reads V8 values into any `Deserialize` type, accepting the values produced by `Serializer`.
+///
+/// Sequences are read from an Array or a `Set`, maps from a `Map` or the own properties of an object, and structs from
+/// an object or a `Map`. Conversion errors inside objects, arrays and maps carry the path to the offending value, like
+/// the derives do.
+pub struct Deserializer<'a, 's> {
+    input: v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'s, v8::Context>,
+    mode: ConversionMode,
+}
+
+impl<'a, 's> Deserializer<'a, 's> {
+    pub fn new(
+        scope: &'a mut v8::HandleScope<'s, v8::Context>,
+        input: v8::Local<'a, v8::Value>,
+        mode: ConversionMode,
+    ) -> Self {
+        Self { input, scope, mode }
+    }
+
+    fn convert<T: TryFromValue>(&mut self) -> Result<T> {
+        T::try_from_value_with(&self.input, self.scope, self.mode)
+    }
+}
+
+/// Converts a part of the input with `seed`, recording where it sits when the conversion fails
+fn deserialize_at<'de, S: DeserializeSeed<'de>>(
+    seed: S,
+    segment: PathSegment,
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+) -> Result<S::Value> {
+    convert_at(segment, input, scope, mode, |deserializer| {
+        seed.deserialize(deserializer)
+    })
+}
+
+/// Converts a part of the input with `convert`, recording where it sits when the conversion fails
+fn convert_at<T>(
+    segment: PathSegment,
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+    mode: ConversionMode,
+    convert: impl FnOnce(Deserializer<'_, '_>) -> Result<T>,
+) -> Result<T> {
+    convert(Deserializer::new(scope, input, mode)).map_err(|error| {
+        error.at(segment, std::any::type_name::<T>(), || {
+            input.type_of(scope).to_rust_string_lossy(scope)
+        })
+    })
+}
+
+/// Implements the `deserialize_*` methods that convert with `TryFromValue`
+macro_rules! deserialize_try_from_value {
+    ($($method:ident($t:ty) => $visit:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+                visitor.$visit(self.convert::<$t>()?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_, '_> {
+    type Error = Error;
+
+    deserialize_try_from_value!(
+        deserialize_bool(bool) => visit_bool,
+        deserialize_i8(i8) => visit_i8,
+        deserialize_i16(i16) => visit_i16,
+        deserialize_i32(i32) => visit_i32,
+        deserialize_i64(i64) => visit_i64,
+        deserialize_i128(i128) => visit_i128,
+        deserialize_u8(u8) => visit_u8,
+        deserialize_u16(u16) => visit_u16,
+        deserialize_u32(u32) => visit_u32,
+        deserialize_u64(u64) => visit_u64,
+        deserialize_u128(u128) => visit_u128,
+        deserialize_f32(f32) => visit_f32,
+        deserialize_f64(f64) => visit_f64,
+        deserialize_char(char) => visit_char,
+        deserialize_str(String) => visit_string,
+        deserialize_string(String) => visit_string,
+        deserialize_identifier(String) => visit_string
+    );
+
+    /// Reads the value as the type it has in JS; integral Numbers are visited as integers
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        let input = self.input;
+        match () {
+            () if input.is_null_or_undefined() => visitor.visit_unit(),
+            () if input.is_boolean() => visitor.visit_bool(input.is_true()),
+            () if input.is_string() => visitor.visit_string(input.to_rust_string_lossy(self.scope)),
+            () if input.is_big_int() => match self.convert::<i64>() {
+                Ok(integer) => visitor.visit_i64(integer),
+                Err(_) => match self.convert::<u64>() {
+                    Ok(integer) => visitor.visit_u64(integer),
+                    Err(_) => visitor.visit_i128(self.convert()?),
+                },
+            },
+            () if input.is_number() => {
+                let number = input.number_value(self.scope).unwrap_or(f64::NAN);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+                if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER as f64 {
+                    visitor.visit_i64(number as i64)
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            () if input.is_array() || input.is_set() => self.deserialize_seq(visitor),
+            () if input.is_array_buffer() || input.is_array_buffer_view() => self.deserialize_byte_buf(visitor),
+            () if input.is_object() && !input.is_function() => self.deserialize_map(visitor),
+            () => Err(Error::UnsupportedValueType),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        visitor.visit_byte_buf(self.convert::<ByteBuf>()?.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.input.is_null_or_undefined() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    /// Reads `null` and `undefined`, the values `Serializer` produces; like the derived unit structs, objects are
+    /// accepted as well unless converting strictly
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match () {
+            () if self.input.is_null_or_undefined() => visitor.visit_unit(),
+            () if self.mode.is_strict() => Err(Error::ExpectedNull),
+            () if self.input.is_object() => visitor.visit_unit(),
+            () => Err(Error::ExpectedObject),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Reads the elements of an Array, or the values of a `Set` in insertion order
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let array: v8::Local<v8::Array> = match self.input.try_cast::<v8::Set>() {
+            Ok(set) => set.as_array(self.scope),
+            Err(_) => self.input.try_cast().map_err(|_| Error::ExpectedArray)?,
+        };
+        let elements = (0..array.length())
+            .map(|i| {
+                let element = array
+                    .get_index(self.scope, i)
+                    .unwrap_or_else(|| v8::undefined(self.scope).into());
+                (i, element)
+            })
+            .collect::<Vec<_>>();
+        visitor.visit_seq(ArrayAccess {
+            elements: elements.into_iter(),
+            scope: self.scope,
+            mode: self.mode,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let key_mode = map_key_mode(self.input, self.mode);
+        let entries = entries(self.input, self.scope)?;
+        visitor.visit_map(EntryAccess {
+            entries: entries.into_iter(),
+            value: None,
+            scope: self.scope,
+            key_mode,
+            mode: self.mode,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if self.input.is_map() {
+            return self.deserialize_map(visitor);
+        }
+
+        if !self.input.is_object() {
+            return Err(Error::ExpectedObject);
+        }
+        let properties = entries(self.input, self.scope)?;
+        visitor.visit_map(FieldAccess {
+            properties: properties.into_iter(),
+            value: None,
+            scope: self.scope,
+            mode: self.mode,
+        })
+    }
+
+    /// Reads a unit variant from a string, any other variant from an object with the variant as its only property
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if self.input.is_string() {
+            let variant = self.input.to_rust_string_lossy(self.scope);
+            return visitor.visit_enum(variant.into_deserializer());
+        }
+
+        let mut entries = entries(self.input, self.scope).map_err(|_| Error::ExpectedVariant)?;
+        let (Some((variant, content)), None) = (entries.pop(), entries.pop()) else {
+            return Err(Error::ExpectedVariant);
+        };
+        let variant = variant.to_rust_string_lossy(self.scope);
+        visitor.visit_enum(TaggedVariant {
+            variant,
+            content,
+            scope: self.scope,
+            mode: self.mode,
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+/// The elements of an Array, with their index
+struct ArrayAccess<'a, 's> {
+    elements: std::vec::IntoIter<(u32, v8::Local<'a, v8::Value>)>,
+    scope: &'a mut v8::HandleScope<'s, v8::Context>,
+    mode: ConversionMode,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        let Some((i, element)) = self.elements.next() else {
+            return Ok(None);
+        };
+        deserialize_at(seed, PathSegment::Index(i), element, self.scope, self.mode).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+/// The entries of a `Map`, or the own properties of an object
+struct EntryAccess<'a, 's> {
+    entries: std::vec::IntoIter<(v8::Local<'a, v8::Value>, v8::Local<'a, v8::Value>)>,
+    value: Option<(PathSegment, v8::Local<'a, v8::Value>)>,
+    scope: &'a mut v8::HandleScope<'s, v8::Context>,
+    key_mode: ConversionMode,
+    mode: ConversionMode,
+}
+
+impl<'de> de::MapAccess<'de> for EntryAccess<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        let segment = PathSegment::Key(key.to_rust_string_lossy(self.scope));
+        self.value = Some((segment.clone(), value));
+        deserialize_at(seed, segment, key, self.scope, self.key_mode).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let (segment, value) = self
+            .value
+            .take()
+            .ok_or_else(|| Error::Custom("next_value_seed called before next_key_seed".to_string()))?;
+        deserialize_at(seed, segment, value, self.scope, self.mode)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// The fields of a struct, read from the own properties of an object; properties that are `undefined` are skipped,
+/// so serde reports them as missing or fills in their default, and the others are all handed to serde, which ignores,
+/// rejects or collects the unknown ones as the struct asks
+struct FieldAccess<'a, 's> {
+    properties: std::vec::IntoIter<(v8::Local<'a, v8::Value>, v8::Local<'a, v8::Value>)>,
+    value: Option<(String, v8::Local<'a, v8::Value>)>,
+    scope: &'a mut v8::HandleScope<'s, v8::Context>,
+    mode: ConversionMode,
+}
+
+impl<'de> de::MapAccess<'de> for FieldAccess<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        for (key, value) in self.properties.by_ref() {
+            if value.is_undefined() {
+                continue;
+            }
+
+            let field = key.to_rust_string_lossy(self.scope);
+            self.value = Some((field.clone(), value));
+            return seed.deserialize(field.into_deserializer()).map(Some);
+        }
+
+        Ok(None)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let (field, value) = self
+            .value
+            .take()
+            .ok_or_else(|| Error::Custom("next_value_seed called before next_key_seed".to_string()))?;
+        deserialize_at(seed, PathSegment::Field(field), value, self.scope, self.mode)
+    }
+}
+
+/// An enum variant given as `{ variant: content }`
+struct TaggedVariant<'a, 's> {
+    variant: String,
+    content: v8::Local<'a, v8::Value>,
+    scope: &'a mut v8::HandleScope<'s, v8::Context>,
+    mode: ConversionMode,
+}
+
+impl<'de> de::EnumAccess<'de> for TaggedVariant<'_, '_> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<(T::Value, Self)> {
+        let variant = seed.deserialize(de::value::StrDeserializer::<Error>::new(&self.variant))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for TaggedVariant<'_, '_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        deserialize_at(
+            seed,
+            PathSegment::Field(self.variant),
+            self.content,
+            self.scope,
+            self.mode,
+        )
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        let segment = PathSegment::Field(self.variant);
+        convert_at(segment, self.content, self.scope, self.mode, |deserializer| {
+            de::Deserializer::deserialize_seq(deserializer, visitor)
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        let segment = PathSegment::Field(self.variant);
+        convert_at(segment, self.content, self.scope, self.mode, |deserializer| {
+            de::Deserializer::deserialize_struct(deserializer, "", fields, visitor)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_v8, from_v8_with, to_v8};
+    use crate::{errors::Error, from::ConversionMode, into::IntoValue, setup};
+    use deno_core::v8;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum Figure {
+        Point,
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Drawing {
+        title: String,
+        id: u64,
+        shapes: Vec<Figure>,
+        layers: BTreeMap<String, i32>,
+        note: Option<String>,
+        #[serde(default)]
+        scale: u8,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Marker;
+
+    #[derive(serde::Serialize)]
+    struct Point3 {
+        x: i32,
+        y: i32,
+        z: i32,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Point {
+        #[allow(dead_code)]
+        x: i32,
+        #[allow(dead_code)]
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Origin {
+        x: i32,
+        #[serde(flatten)]
+        rest: BTreeMap<String, i32>,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(untagged)]
+    enum Labels {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    #[test]
+    fn can_round_trip_serde_types() {
+        setup::with_scope(|scope| {
+            let drawing = Drawing {
+                title: "plan".to_string(),
+                id: u64::MAX,
+                shapes: vec![Figure::Point, Figure::Circle(1.5), Figure::Rect { width: 3, height: 4 }],
+                layers: BTreeMap::from([("ground".to_string(), 0), ("roof".to_string(), 2)]),
+                note: None,
+                scale: 2,
+            };
+            let value = to_v8(scope, &drawing).unwrap();
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "id").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_big_int());
+            let key = v8::String::new(scope, "layers").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_map());
+            let key = v8::String::new(scope, "note").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_null());
+            assert_eq!(from_v8::<Drawing>(scope, value).unwrap(), drawing);
+
+            // absent fields take their serde default, and errors carry their path
+            let key = v8::String::new(scope, "scale").unwrap();
+            let undefined = v8::undefined(scope).into();
+            object.set(scope, key.into(), undefined);
+            assert_eq!(from_v8::<Drawing>(scope, value).unwrap().scale, 0);
+            let key = v8::String::new(scope, "shapes").unwrap();
+            let unknown = v8::String::new(scope, "Hexagon").unwrap().into();
+            let shapes = v8::Array::new_with_elements(scope, &[unknown]);
+            object.set(scope, key.into(), shapes.into());
+            let error = from_v8::<Drawing>(scope, value).unwrap_err();
+            assert_eq!(error.path().unwrap().to_string(), "shapes[0]");
+
+            let value = v8::String::new(scope, "7").unwrap().into();
+            assert_eq!(from_v8::<u32>(scope, value).unwrap(), 7);
+            let error = from_v8_with::<u32>(scope, value, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedU32));
+        });
+    }
+
+    #[test]
+    fn can_read_units_from_null() {
+        setup::with_scope(|scope| {
+            let value = to_v8(scope, &()).unwrap();
+            assert!(value.is_null());
+            from_v8::<()>(scope, value).unwrap();
+            let value = v8::undefined(scope).into();
+            from_v8_with::<Marker>(scope, value, ConversionMode::Strict).unwrap();
+
+            // objects only when converting leniently, and never other values
+            let value = v8::Object::new(scope).into();
+            from_v8::<Marker>(scope, value).unwrap();
+            let error = from_v8_with::<Marker>(scope, value, ConversionMode::Strict).unwrap_err();
+            assert!(matches!(error, Error::ExpectedNull));
+            let value = v8::String::new(scope, "unit").unwrap().into();
+            let error = from_v8::<()>(scope, value).unwrap_err();
+            assert!(matches!(error, Error::ExpectedObject));
+        });
+    }
+
+    #[test]
+    fn can_see_unknown_fields() {
+        setup::with_scope(|scope| {
+            let value = to_v8(scope, &Point3 { x: 1, y: 2, z: 3 }).unwrap();
+            let error = from_v8::<Point>(scope, value).unwrap_err();
+            assert!(matches!(error, Error::Custom(message) if message.contains("unknown field `z`")));
+
+            let origin = from_v8::<Origin>(scope, value).unwrap();
+            assert_eq!(origin.x, 1);
+            assert_eq!(
+                origin.rest,
+                BTreeMap::from([("y".to_string(), 2), ("z".to_string(), 3)])
+            );
+        });
+    }
+
+    #[test]
+    fn can_read_sets_as_sequences() {
+        setup::with_scope(|scope| {
+            let set = BTreeSet::from(["a".to_string(), "b".to_string()]);
+            let value = set.clone().into_value(scope);
+            assert!(value.is_set());
+            assert_eq!(from_v8::<BTreeSet<String>>(scope, value).unwrap(), set);
+            // an untagged enum reads the Set with `deserialize_any`
+            let labels = from_v8::<Labels>(scope, value).unwrap();
+            assert_eq!(labels, Labels::Many(vec!["a".to_string(), "b".to_string()]));
+
+            let value = v8::String::new(scope, "a").unwrap().into();
+            assert_eq!(from_v8::<Labels>(scope, value).unwrap(), Labels::One("a".to_string()));
+        });
+    }
+}
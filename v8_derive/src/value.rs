@@ -0,0 +1,507 @@
+//! Dynamic values: any JS value that can be structured-cloned, held in Rust without losing information.
+
+use crate::{
+    buffer::{array_buffer, copy_bytes},
+    errors::{Error, Located, PathSegment, Result},
+    helpers::entries,
+    into::IntoValue,
+};
+use deno_core::v8;
+
+/// A JS value of any type, converted from and into the same JS value.
+///
+/// Every value that can be structured-cloned is supported, as well as symbols. Functions, promises, proxies,
+/// boxed primitives, and `SharedArrayBuffer`s and the typed arrays and `DataView`s over them fail with
+/// `UnsupportedValueType`, and values that contain themselves with `CyclicValue`.
+///
+/// Unlike a structured clone, a `JsValue` is a tree and does not keep the identity of objects: an object reached
+/// twice, e.g. through two properties, is converted into two equal copies, which become two distinct objects
+/// when converted back into JS.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum JsValue {
+    #[default]
+    Undefined,
+    Null,
+    Bool(bool),
+    /// Any Number, including `NaN`, the infinities and `-0`
+    Number(f64),
+    /// A `BigInt` of any size, as its magnitude in 64-bit words, least significant first
+    BigInt {
+        negative: bool,
+        words: Vec<u64>,
+    },
+    String(String),
+    /// A symbol and its description; converting it into a JS value creates a new, unique symbol
+    Symbol(Option<String>),
+    /// A `Date`, as milliseconds since the Unix epoch; `NaN` for an invalid date
+    Date(f64),
+    RegExp {
+        source: String,
+        flags: String,
+    },
+    /// An `Error`, `TypeError` or any other native error
+    Error {
+        name: String,
+        message: String,
+    },
+    ArrayBuffer(Vec<u8>),
+    /// A typed array or `DataView` and the bytes it views
+    TypedArray {
+        kind: TypedArrayKind,
+        bytes: Vec<u8>,
+    },
+    /// An Array; holes are read as `Undefined`
+    Array(Vec<JsValue>),
+    /// The entries of a `Map`, in insertion order
+    Map(Vec<(JsValue, JsValue)>),
+    /// The values of a `Set`, in insertion order
+    Set(Vec<JsValue>),
+    /// The own enumerable properties of any other object, in JS order
+    Object(Vec<(String, JsValue)>),
+}
+
+impl From<bool> for JsValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for JsValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<String> for JsValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for JsValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<Vec<JsValue>> for JsValue {
+    fn from(elements: Vec<JsValue>) -> Self {
+        Self::Array(elements)
+    }
+}
+
+/// Defines `TypedArrayKind`, with a variant for each typed array `$array` of elements `$t`
+macro_rules! typed_array_kind {
+    ($($kind:ident($t:ty) => $array:ident if $is:ident),*) => {
+        /// The type of a typed array, or `DataView`
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum TypedArrayKind {
+            $($kind,)*
+            DataView,
+        }
+
+        impl TypedArrayKind {
+            fn of(input: v8::Local<'_, v8::Value>) -> Option<Self> {
+                $(
+                    if input.$is() {
+                        return Some(Self::$kind);
+                    }
+                )*
+                input.is_data_view().then_some(Self::DataView)
+            }
+
+            /// A new view of this kind over `bytes`; bytes left over after the last whole element are not viewed
+            pub(crate) fn view<'a>(self, scope: &mut v8::HandleScope<'a>, bytes: Vec<u8>) -> v8::Local<'a, v8::Value> {
+                let byte_length = bytes.len();
+                let buffer = array_buffer(scope, bytes);
+                let view = match self {
+                    $(Self::$kind => v8::$array::new(scope, buffer, 0, byte_length / size_of::<$t>()).map(Into::into),)*
+                    Self::DataView => Some(v8::DataView::new(scope, buffer, 0, byte_length).into()),
+                };
+                view.unwrap_or_else(|| v8::undefined(scope).into())
+            }
+        }
+    };
+}
+
+typed_array_kind! {
+    Int8(i8) => Int8Array if is_int8_array,
+    Uint8(u8) => Uint8Array if is_uint8_array,
+    Uint8Clamped(u8) => Uint8ClampedArray if is_uint8_clamped_array,
+    Int16(i16) => Int16Array if is_int16_array,
+    Uint16(u16) => Uint16Array if is_uint16_array,
+    Int32(i32) => Int32Array if is_int32_array,
+    Uint32(u32) => Uint32Array if is_uint32_array,
+    Float32(f32) => Float32Array if is_float32_array,
+    Float64(f64) => Float64Array if is_float64_array,
+    BigInt64(i64) => BigInt64Array if is_big_int64_array,
+    BigUint64(u64) => BigUint64Array if is_big_uint64_array
+}
+
+/// Converts any supported JS value
+///
+/// # Errors
+/// `UnsupportedValueType` and `CyclicValue` as described on `JsValue`, placed at the offending part of the value.
+pub(crate) fn try_from_value(
+    input: v8::Local<'_, v8::Value>,
+    scope: &mut v8::HandleScope<'_, v8::Context>,
+) -> Result<JsValue> {
+    read(input, scope, &mut Vec::new())
+}
+
+/// Converts `input`; `ancestors` are the objects it is nested in, to detect cycles
+fn read<'a, 's: 'a>(
+    input: v8::Local<'a, v8::Value>,
+    scope: &mut v8::HandleScope<'s, v8::Context>,
+    ancestors: &mut Vec<v8::Local<'a, v8::Value>>,
+) -> Result<JsValue> {
+    match () {
+        () if input.is_undefined() => return Ok(JsValue::Undefined),
+        () if input.is_null() => return Ok(JsValue::Null),
+        () if input.is_boolean() => return Ok(JsValue::Bool(input.is_true())),
+        () if input.is_number() => return Ok(JsValue::Number(input.number_value(scope).unwrap_or(f64::NAN))),
+        () if input.is_string() => return Ok(JsValue::String(input.to_rust_string_lossy(scope))),
+        () if input.is_big_int() => {
+            let big_int: v8::Local<v8::BigInt> = input.try_cast()?;
+            let mut words = vec![0; big_int.word_count()];
+            let negative = big_int.to_words_array(&mut words).0;
+            return Ok(JsValue::BigInt { negative, words });
+        }
+        () if input.is_symbol() => {
+            let symbol: v8::Local<v8::Symbol> = input.try_cast()?;
+            let description = symbol.description(scope);
+            let description = (!description.is_undefined()).then(|| description.to_rust_string_lossy(scope));
+            return Ok(JsValue::Symbol(description));
+        }
+        () => {}
+    }
+
+    if !input.is_object()
+        || input.is_function()
+        || input.is_promise()
+        || input.is_proxy()
+        || input.is_shared_array_buffer()
+        || input.is_boolean_object()
+        || input.is_number_object()
+        || input.is_string_object()
+        || input.is_big_int_object()
+        || input.is_symbol_object()
+    {
+        return Err(Error::UnsupportedValueType);
+    }
+
+    if input.is_date() {
+        let date: v8::Local<v8::Date> = input.try_cast()?;
+        return Ok(JsValue::Date(date.value_of()));
+    }
+    if input.is_reg_exp() {
+        let source = property(input, "source", scope)?;
+        let flags = property(input, "flags", scope)?;
+        return Ok(JsValue::RegExp { source, flags });
+    }
+    if input.is_native_error() {
+        let name = property(input, "name", scope)?;
+        let message = property(input, "message", scope)?;
+        return Ok(JsValue::Error { name, message });
+    }
+    if input.is_array_buffer() {
+        return Ok(JsValue::ArrayBuffer(copy_bytes(input)?));
+    }
+    if let Some(kind) = TypedArrayKind::of(input) {
+        // a copy of a view over a SharedArrayBuffer would no longer be shared
+        let view: v8::Local<v8::ArrayBufferView> = input.try_cast()?;
+        if view.buffer(scope).is_some_and(|buffer| buffer.is_shared_array_buffer()) {
+            return Err(Error::UnsupportedValueType);
+        }
+        let bytes = copy_bytes(input)?;
+        return Ok(JsValue::TypedArray { kind, bytes });
+    }
+
+    if ancestors.iter().any(|ancestor| ancestor.strict_equals(input)) {
+        return Err(Error::CyclicValue);
+    }
+
+    ancestors.push(input);
+    let value = read_object(input, scope, ancestors);
+    ancestors.pop();
+    value
+}
+
+/// Converts an Array, `Map`, `Set` or any other object, with all the values it holds
+fn read_object<'a, 's: 'a>(
+    input: v8::Local<'a, v8::Value>,
+    scope: &mut v8::HandleScope<'s, v8::Context>,
+    ancestors: &mut Vec<v8::Local<'a, v8::Value>>,
+) -> Result<JsValue> {
+    if input.is_array() || input.is_set() {
+        let array: v8::Local<v8::Array> = match input.try_cast::<v8::Set>() {
+            Ok(set) => set.as_array(scope),
+            Err(_) => input.try_cast()?,
+        };
+        let elements = (0..array.length())
+            .map(|i| {
+                let element = array.get_index(scope, i).unwrap_or_else(|| v8::undefined(scope).into());
+                read_at(PathSegment::Index(i), element, scope, ancestors)
+            })
+            .collect::<Result<_>>()?;
+        return Ok(if input.is_set() {
+            JsValue::Set(elements)
+        } else {
+            JsValue::Array(elements)
+        });
+    }
+
+    let entries = entries(input, scope)?;
+    if input.is_map() {
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| {
+                let segment = PathSegment::Key(key.to_rust_string_lossy(scope));
+                let key = read_at(segment.clone(), key, scope, ancestors)?;
+                Ok((key, read_at(segment, value, scope, ancestors)?))
+            })
+            .collect::<Result<_>>()?;
+        return Ok(JsValue::Map(entries));
+    }
+
+    let properties = entries
+        .into_iter()
+        .map(|(key, value)| {
+            let name = key.to_rust_string_lossy(scope);
+            let value = read_at(PathSegment::Field(name.clone()), value, scope, ancestors)?;
+            Ok((name, value))
+        })
+        .collect::<Result<_>>()?;
+    Ok(JsValue::Object(properties))
+}
+
+/// Converts a part of a value, recording where it sits when the conversion fails
+fn read_at<'a, 's: 'a>(
+    segment: PathSegment,
+    input: v8::Local<'a, v8::Value>,
+    scope: &mut v8::HandleScope<'s, v8::Context>,
+    ancestors: &mut Vec<v8::Local<'a, v8::Value>>,
+) -> Result<JsValue> {
+    read(input, scope, ancestors).map_err(|error| {
+        error.at(segment, std::any::type_name::<JsValue>(), || {
+            input.type_of(scope).to_rust_string_lossy(scope)
+        })
+    })
+}
+
+/// The property `name` of an object, as a string
+fn property(input: v8::Local<'_, v8::Value>, name: &str, scope: &mut v8::HandleScope<'_>) -> Result<String> {
+    let object: v8::Local<v8::Object> = input.try_cast()?;
+    let key = v8::String::new(scope, name).ok_or_else(|| Error::InvalidField(name.to_string()))?;
+    let value = object
+        .get(scope, key.into())
+        .ok_or_else(|| Error::FieldNotFound(name.to_string()))?;
+    Ok(value.to_rust_string_lossy(scope))
+}
+
+/// A new Object with the given own properties; they are defined rather than assigned, so that a `__proto__`
+/// property stays a property instead of replacing the prototype
+pub(crate) fn object<'a>(
+    scope: &mut v8::HandleScope<'a>,
+    properties: Vec<(String, JsValue)>,
+) -> v8::Local<'a, v8::Value> {
+    let object = v8::Object::new(scope);
+
+    for (key, value) in properties {
+        let Some(js_key) = v8::String::new(scope, &key) else {
+            continue;
+        };
+        let js_val = value.into_value(scope);
+        object.create_data_property(scope, js_key.into(), js_val);
+    }
+
+    object.into()
+}
+
+/// A new symbol with the given description
+pub(crate) fn symbol<'a>(scope: &mut v8::HandleScope<'a>, description: Option<&str>) -> v8::Local<'a, v8::Value> {
+    let description = description.and_then(|description| v8::String::new(scope, description));
+    v8::Symbol::new(scope, description).into()
+}
+
+/// A new `RegExp`; unknown flags are ignored, and an invalid pattern gives `undefined`
+pub(crate) fn reg_exp<'a>(scope: &mut v8::HandleScope<'a>, source: &str, flags: &str) -> v8::Local<'a, v8::Value> {
+    let creation_flags = flags
+        .chars()
+        .fold(v8::RegExpCreationFlags::empty(), |creation_flags, flag| {
+            creation_flags
+                | match flag {
+                    'd' => v8::RegExpCreationFlags::HAS_INDICES,
+                    'g' => v8::RegExpCreationFlags::GLOBAL,
+                    'i' => v8::RegExpCreationFlags::IGNORE_CASE,
+                    'l' => v8::RegExpCreationFlags::LINEAR,
+                    'm' => v8::RegExpCreationFlags::MULTILINE,
+                    's' => v8::RegExpCreationFlags::DOT_ALL,
+                    'u' => v8::RegExpCreationFlags::UNICODE,
+                    'v' => v8::RegExpCreationFlags::UNICODE_SETS,
+                    'y' => v8::RegExpCreationFlags::STICKY,
+                    _ => v8::RegExpCreationFlags::empty(),
+                }
+        });
+
+    v8::String::new(scope, source)
+        .and_then(|source| v8::RegExp::new(scope, source, creation_flags))
+        .map_or_else(|| v8::undefined(scope).into(), Into::into)
+}
+
+/// A new native error; the standard error types are created with their own constructor, any other `name` is set
+/// on an `Error`
+pub(crate) fn error<'a>(scope: &mut v8::HandleScope<'a>, name: &str, message: &str) -> v8::Local<'a, v8::Value> {
+    let Some(message) = v8::String::new(scope, message) else {
+        return v8::undefined(scope).into();
+    };
+
+    match name {
+        "RangeError" => v8::Exception::range_error(scope, message),
+        "ReferenceError" => v8::Exception::reference_error(scope, message),
+        "SyntaxError" => v8::Exception::syntax_error(scope, message),
+        "TypeError" => v8::Exception::type_error(scope, message),
+        "Error" => v8::Exception::error(scope, message),
+        _ => {
+            let error = v8::Exception::error(scope, message);
+            if let (Ok(object), Some(key), Some(value)) = (
+                error.try_cast::<v8::Object>(),
+                v8::String::new(scope, "name"),
+                v8::String::new(scope, name),
+            ) {
+                object.set(scope, key.into(), value.into());
+            }
+            error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsValue, TypedArrayKind};
+    use crate::{errors::Error, into::IntoValue, setup, TryFromValue};
+    use deno_core::v8;
+
+    #[test]
+    fn can_round_trip_any_value() {
+        setup::with_scope(|scope| {
+            let any = JsValue::Object(vec![
+                ("missing".to_string(), JsValue::Undefined),
+                ("ratio".to_string(), JsValue::Number(-0.0)),
+                (
+                    "huge".to_string(),
+                    JsValue::BigInt {
+                        negative: true,
+                        words: vec![0, 0, 1],
+                    },
+                ),
+                ("at".to_string(), JsValue::Date(1_709_199_000_000.0)),
+                (
+                    "pattern".to_string(),
+                    JsValue::RegExp {
+                        source: "a+b".to_string(),
+                        flags: "gi".to_string(),
+                    },
+                ),
+                (
+                    "failure".to_string(),
+                    JsValue::Error {
+                        name: "RangeError".to_string(),
+                        message: "too far".to_string(),
+                    },
+                ),
+                (
+                    "samples".to_string(),
+                    JsValue::TypedArray {
+                        kind: TypedArrayKind::Int16,
+                        bytes: vec![1, 0, 255, 255],
+                    },
+                ),
+                (
+                    "lookup".to_string(),
+                    JsValue::Map(vec![(
+                        JsValue::Number(1.0),
+                        JsValue::Set(vec!["a".into(), JsValue::Null]),
+                    )]),
+                ),
+                (
+                    "list".to_string(),
+                    vec![true.into(), JsValue::ArrayBuffer(vec![7])].into(),
+                ),
+            ]);
+            let value = any.clone().into_value(scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "huge").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_big_int());
+            let key = v8::String::new(scope, "samples").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_int16_array());
+            let key = v8::String::new(scope, "failure").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_native_error());
+            assert_eq!(JsValue::try_from_value(&value, scope).unwrap(), any);
+
+            let value = v8::Number::new(scope, f64::NAN).into();
+            assert!(matches!(JsValue::try_from_value(&value, scope).unwrap(), JsValue::Number(n) if n.is_nan()));
+            let value = JsValue::Symbol(Some("tag".to_string())).into_value(scope);
+            assert!(value.is_symbol());
+            assert_eq!(
+                JsValue::try_from_value(&value, scope).unwrap(),
+                JsValue::Symbol(Some("tag".to_string()))
+            );
+
+            // a value that contains itself has no tree to convert into
+            let object = v8::Object::new(scope);
+            let key = v8::String::new(scope, "me").unwrap();
+            object.set(scope, key.into(), object.into());
+            let error = JsValue::try_from_value(&object.into(), scope).unwrap_err();
+            assert_eq!(error.path().unwrap().to_string(), "me");
+            assert!(matches!(error, Error::AtPath { source, .. } if matches!(*source, Error::CyclicValue)));
+        });
+    }
+
+    #[test]
+    fn converts_shared_objects_into_copies() {
+        setup::with_scope(|scope| {
+            // { left: shared, right: shared }
+            let shared = v8::Object::new(scope);
+            let key = v8::String::new(scope, "n").unwrap();
+            let n = v8::Number::new(scope, 1.0);
+            shared.set(scope, key.into(), n.into());
+            let object = v8::Object::new(scope);
+            for name in ["left", "right"] {
+                let key = v8::String::new(scope, name).unwrap();
+                object.set(scope, key.into(), shared.into());
+            }
+
+            let copy = JsValue::Object(vec![("n".to_string(), JsValue::Number(1.0))]);
+            let any = JsValue::try_from_value(&object.into(), scope).unwrap();
+            assert_eq!(
+                any,
+                JsValue::Object(vec![("left".to_string(), copy.clone()), ("right".to_string(), copy)])
+            );
+
+            // the identity is lost: converting back gives two distinct objects
+            let value = any.into_value(scope).to_object(scope).unwrap();
+            let key = v8::String::new(scope, "left").unwrap();
+            let left = value.get(scope, key.into()).unwrap();
+            let key = v8::String::new(scope, "right").unwrap();
+            let right = value.get(scope, key.into()).unwrap();
+            assert!(!left.strict_equals(right));
+        });
+    }
+
+    #[test]
+    fn keeps_a_proto_property_as_a_property() {
+        setup::with_scope(|scope| {
+            // like JSON.parse('{"__proto__": {"admin": true}}')
+            let any = JsValue::Object(vec![(
+                "__proto__".to_string(),
+                JsValue::Object(vec![("admin".to_string(), JsValue::Bool(true))]),
+            )]);
+            let value = any.clone().into_value(scope);
+            let object = value.to_object(scope).unwrap();
+            let key = v8::String::new(scope, "admin").unwrap();
+            assert!(object.get(scope, key.into()).unwrap().is_undefined());
+            assert_eq!(JsValue::try_from_value(&value, scope).unwrap(), any);
+        });
+    }
+}
@@ -0,0 +1,9 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+#[v8(content = "value")]
+enum Message {
+    Text(String),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+enum Message {
+    Move(i32, #[v8(default)] i32),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use v8_derive::macros::FromValue;
+
+#[derive(Default, FromValue)]
+#[v8(default)]
+struct Point(i32, i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use v8_derive::macros::IntoValue;
+
+#[derive(IntoValue)]
+#[v8(tag = "type")]
+enum Shape {
+    Point(i32, i32),
+}
+
+fn main() {}
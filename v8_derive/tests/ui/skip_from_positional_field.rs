@@ -0,0 +1,6 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+struct Pair(i32, #[v8(skip_from)] i32);
+
+fn main() {}
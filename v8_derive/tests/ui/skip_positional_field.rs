@@ -0,0 +1,6 @@
+use v8_derive::macros::IntoValue;
+
+#[derive(IntoValue)]
+struct Pair(i32, #[v8(skip_into)] i32);
+
+fn main() {}
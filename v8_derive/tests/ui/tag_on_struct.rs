@@ -0,0 +1,9 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+#[v8(tag = "type")]
+struct Person {
+    name: String,
+}
+
+fn main() {}
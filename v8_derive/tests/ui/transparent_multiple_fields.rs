@@ -0,0 +1,10 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+#[v8(transparent)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+union Number {
+    int: i32,
+    float: f32,
+}
+
+fn main() {}
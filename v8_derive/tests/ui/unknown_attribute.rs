@@ -0,0 +1,9 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+struct Person {
+    #[v8(flatten)]
+    name: String,
+}
+
+fn main() {}
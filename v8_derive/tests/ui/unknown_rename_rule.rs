@@ -0,0 +1,9 @@
+use v8_derive::macros::IntoValue;
+
+#[derive(IntoValue)]
+#[v8(rename_all = "Title Case")]
+struct Person {
+    first_name: String,
+}
+
+fn main() {}
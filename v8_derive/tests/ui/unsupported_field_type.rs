@@ -0,0 +1,9 @@
+use v8_derive::macros::FromValue;
+
+#[derive(FromValue)]
+struct Callback {
+    name: String,
+    callback: fn() -> i32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use v8_derive::macros::IntoValue;
+
+#[derive(IntoValue)]
+struct Buffer {
+    data: *const (),
+}
+
+fn main() {}
@@ -0,0 +1,338 @@
+//! Parsing of the `#[v8(...)]` attributes.
+
+use crate::case::RenameRule;
+use syn::{ext::IdentExt, punctuated::Punctuated, spanned::Spanned, Attribute, LitStr, Token};
+
+/// The way an enum is represented as a JavaScript value
+pub(crate) enum Tagging {
+    /// `{ "Variant": content }`; unit variants are plain strings
+    External,
+    /// `{ tag: "Variant", ...fields }`
+    Internal { tag: String },
+    /// `{ tag: "Variant", content: content }`
+    Adjacent { tag: String, content: String },
+    /// `content` only; the first variant that converts wins
+    Untagged,
+}
+
+/// The source of a default value: `#[v8(default)]` or `#[v8(default = "path")]`
+pub(crate) enum DefaultValue {
+    Trait(proc_macro2::Span),
+    Path(syn::Path),
+}
+
+impl DefaultValue {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        if meta.input.peek(Token![=]) {
+            let path: LitStr = meta.value()?.parse()?;
+            Ok(Self::Path(path.parse()?))
+        } else {
+            Ok(Self::Trait(meta.path.span()))
+        }
+    }
+
+    /// An expression evaluating to the default value
+    pub(crate) fn quote(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Trait(_) => quote::quote! { ::core::default::Default::default() },
+            Self::Path(path) => quote::quote! { #path() },
+        }
+    }
+
+    pub(crate) fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Trait(span) => *span,
+            Self::Path(path) => path.span(),
+        }
+    }
+}
+
+/// The conversion mode forced by `#[v8(strict)]` or `#[v8(lenient)]`
+#[derive(Clone, Copy)]
+pub(crate) enum Mode {
+    Strict,
+    Lenient,
+}
+
+impl Mode {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> Option<Self> {
+        if meta.path.is_ident("strict") {
+            Some(Self::Strict)
+        } else if meta.path.is_ident("lenient") {
+            Some(Self::Lenient)
+        } else {
+            None
+        }
+    }
+
+    /// The `ConversionMode` to convert with; `__v8_mode` is the mode the conversion was started with
+    pub(crate) fn quote(mode: Option<Self>) -> proc_macro2::TokenStream {
+        match mode {
+            Some(Self::Strict) => quote::quote! { __v8_derive::ConversionMode::Strict },
+            Some(Self::Lenient) => quote::quote! { __v8_derive::ConversionMode::Lenient },
+            None => quote::quote! { __v8_mode },
+        }
+    }
+}
+
+/// The JS representation of a field forced by `#[v8(bigint)]` or `#[v8(number)]` for integers, by
+/// `#[v8(as_object)]` or `#[v8(as_map)]` for maps, or by the functions of the module in `#[v8(with = "path")]`
+pub(crate) enum Repr {
+    BigInt,
+    Number,
+    Object,
+    Map,
+    With(syn::Path),
+}
+
+impl Repr {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<Self>> {
+        let repr = if meta.path.is_ident("bigint") {
+            Self::BigInt
+        } else if meta.path.is_ident("number") {
+            Self::Number
+        } else if meta.path.is_ident("as_object") {
+            Self::Object
+        } else if meta.path.is_ident("as_map") {
+            Self::Map
+        } else if meta.path.is_ident("with") {
+            let path: LitStr = meta.value()?.parse()?;
+            Self::With(path.parse()?)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(repr))
+    }
+}
+
+/// Attributes placed on the struct or enum itself
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub(crate) tag: Option<LitStr>,
+    pub(crate) content: Option<LitStr>,
+    pub(crate) untagged: Option<proc_macro2::Span>,
+    pub(crate) rename_all: Option<RenameRule>,
+    pub(crate) default: Option<DefaultValue>,
+    pub(crate) transparent: Option<proc_macro2::Span>,
+    pub(crate) bound_from: Option<Vec<syn::WherePredicate>>,
+    pub(crate) bound_into: Option<Vec<syn::WherePredicate>>,
+    pub(crate) krate: Option<syn::Path>,
+    pub(crate) mode: Option<Mode>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("v8")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    result.tag = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("content") {
+                    result.content = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("untagged") {
+                    result.untagged = Some(meta.path.span());
+                } else if meta.path.is_ident("rename_all") {
+                    result.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("default") {
+                    result.default = Some(DefaultValue::parse(&meta)?);
+                } else if meta.path.is_ident("transparent") {
+                    result.transparent = Some(meta.path.span());
+                } else if let Some(mode) = Mode::parse(&meta) {
+                    result.mode = Some(mode);
+                } else if meta.path.is_ident("crate") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    result.krate = Some(path.parse()?);
+                } else if meta.path.is_ident("bound") {
+                    if meta.input.peek(Token![=]) {
+                        let bound = parse_bound(&meta.value()?.parse()?)?;
+                        result.bound_from = Some(bound.clone());
+                        result.bound_into = Some(bound);
+                    } else {
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("from") {
+                                result.bound_from = Some(parse_bound(&meta.value()?.parse()?)?);
+                            } else if meta.path.is_ident("into") {
+                                result.bound_into = Some(parse_bound(&meta.value()?.parse()?)?);
+                            } else {
+                                return Err(meta.error("expected `from` or `into`"));
+                            }
+                            Ok(())
+                        })?;
+                    }
+                } else {
+                    return Err(meta.error("unsupported v8 container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    /// Path of the `v8_derive` crate used by the generated code
+    pub(crate) fn krate(&self) -> syn::Path {
+        self.krate.clone().unwrap_or_else(|| syn::parse_quote! { ::v8_derive })
+    }
+
+    /// Resolves the enum representation, rejecting contradicting attributes
+    pub(crate) fn tagging(&self) -> syn::Result<Tagging> {
+        match (&self.tag, &self.content, self.untagged) {
+            (None, None, None) => Ok(Tagging::External),
+            (Some(tag), None, None) => Ok(Tagging::Internal { tag: tag.value() }),
+            (Some(tag), Some(content), None) => Ok(Tagging::Adjacent {
+                tag: tag.value(),
+                content: content.value(),
+            }),
+            (None, None, Some(_)) => Ok(Tagging::Untagged),
+            (None, Some(content), _) => Err(syn::Error::new(
+                content.span(),
+                "`content` requires `tag` to be set as well",
+            )),
+            (Some(_), _, Some(untagged)) => Err(syn::Error::new(untagged, "`untagged` can not be combined with `tag`")),
+        }
+    }
+
+    /// Rejects the attributes that only make sense on structs
+    pub(crate) fn ensure_enum(&self) -> syn::Result<()> {
+        if let Some(default) = &self.default {
+            return Err(syn::Error::new(
+                default.span(),
+                "`default` is only supported on structs",
+            ));
+        }
+        if let Some(transparent) = self.transparent {
+            return Err(syn::Error::new(
+                transparent,
+                "`transparent` is only supported on structs",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Rejects the attributes that only make sense on enums
+    pub(crate) fn ensure_struct(&self) -> syn::Result<()> {
+        if let Some(tag) = &self.tag {
+            return Err(syn::Error::new(tag.span(), "`tag` is only supported on enums"));
+        }
+        if let Some(content) = &self.content {
+            return Err(syn::Error::new(content.span(), "`content` is only supported on enums"));
+        }
+        if let Some(untagged) = self.untagged {
+            return Err(syn::Error::new(untagged, "`untagged` is only supported on enums"));
+        }
+        Ok(())
+    }
+}
+
+/// Attributes placed on a field of a struct or struct variant
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) rename: Option<LitStr>,
+    pub(crate) aliases: Vec<LitStr>,
+    pub(crate) default: Option<DefaultValue>,
+    /// The span of `skip` or `skip_from`
+    pub(crate) skip_from: Option<proc_macro2::Span>,
+    /// The span of `skip` or `skip_into`
+    pub(crate) skip_into: Option<proc_macro2::Span>,
+    pub(crate) skip_into_if: Option<syn::Path>,
+    pub(crate) mode: Option<Mode>,
+    pub(crate) repr: Option<Repr>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("v8")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    result.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("alias") {
+                    result.aliases.push(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    result.default = Some(DefaultValue::parse(&meta)?);
+                } else if meta.path.is_ident("skip") {
+                    result.skip_from = Some(meta.path.span());
+                    result.skip_into = Some(meta.path.span());
+                } else if meta.path.is_ident("skip_from") {
+                    result.skip_from = Some(meta.path.span());
+                } else if meta.path.is_ident("skip_into") {
+                    result.skip_into = Some(meta.path.span());
+                } else if meta.path.is_ident("skip_into_if") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    result.skip_into_if = Some(path.parse()?);
+                } else if let Some(mode) = Mode::parse(&meta) {
+                    result.mode = Some(mode);
+                } else if let Some(repr) = Repr::parse(&meta)? {
+                    result.repr = Some(repr);
+                } else {
+                    return Err(meta.error("unsupported v8 field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    /// The JS property name of the field
+    pub(crate) fn name(&self, identifier: &syn::Ident, rename_all: Option<RenameRule>) -> LitStr {
+        js_name(self.rename.as_ref(), identifier, |name| match rename_all {
+            Some(rule) => rule.apply_to_field(name),
+            None => name.to_string(),
+        })
+    }
+}
+
+/// Attributes placed on an enum variant
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) rename: Option<LitStr>,
+    pub(crate) aliases: Vec<LitStr>,
+    pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl VariantAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("v8")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    result.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("alias") {
+                    result.aliases.push(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename_all") {
+                    result.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                } else {
+                    return Err(meta.error("unsupported v8 variant attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    /// The name used to tag the variant
+    pub(crate) fn name(&self, identifier: &syn::Ident, rename_all: Option<RenameRule>) -> LitStr {
+        js_name(self.rename.as_ref(), identifier, |name| match rename_all {
+            Some(rule) => rule.apply_to_variant(name),
+            None => name.to_string(),
+        })
+    }
+}
+
+/// Parses the where predicates of `#[v8(bound = "...")]`; an empty string adds no bounds at all
+fn parse_bound(lit: &LitStr) -> syn::Result<Vec<syn::WherePredicate>> {
+    let predicates = lit.parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)?;
+    Ok(predicates.into_iter().collect())
+}
+
+fn js_name(rename: Option<&LitStr>, identifier: &syn::Ident, rule: impl Fn(&str) -> String) -> LitStr {
+    match rename {
+        Some(rename) => rename.clone(),
+        None => LitStr::new(&rule(&identifier.unraw().to_string()), identifier.span()),
+    }
+}
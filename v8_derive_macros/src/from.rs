@@ -0,0 +1,519 @@
+//! Expansion of the `FromValue` derive.
+
+use crate::{
+    attr::{ContainerAttrs, FieldAttrs, Mode, Tagging, VariantAttrs},
+    case::RenameRule,
+    helpers::{quote_parse_fn, quote_try_from_value, unsupported_union, with_bounds, wrap_in_const},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DataEnum, DeriveInput, Fields, Variant};
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let identifier = &input.ident;
+
+    let (body, collect) = match &input.data {
+        Data::Struct(syn::DataStruct { fields, .. }) => {
+            attrs.ensure_struct()?;
+            (
+                quote_struct(fields, &attrs, false)?,
+                Some(quote_struct(fields, &attrs, true)?),
+            )
+        }
+        Data::Enum(data) => (
+            quote_enum(identifier, data, &attrs, false)?,
+            Some(quote_enum(identifier, data, &attrs, true)?),
+        ),
+        Data::Union(data) => return Err(unsupported_union(data)),
+    };
+
+    // `#[v8(strict)]` and `#[v8(lenient)]` on the container take precedence over the mode of the call
+    let mode = attrs.mode.map(|mode| {
+        let mode = Mode::quote(Some(mode));
+        quote! { let __v8_mode = #mode; }
+    });
+    // every field of the struct or of the variant is converted before reporting
+    let collect = collect.map(|collect| {
+        quote! {
+            fn try_from_value_collect_with<'__v8>(
+                input: &'__v8 __v8_derive::v8::Local<'__v8, __v8_derive::v8::Value>,
+                scope: &'__v8 mut __v8_derive::v8::HandleScope<'_, __v8_derive::v8::Context>,
+                __v8_mode: __v8_derive::ConversionMode,
+            ) -> ::core::result::Result<Self, ::std::vec::Vec<__v8_derive::errors::Error>>
+            where
+                Self: Sized {
+                    #mode
+                    #collect
+            }
+        }
+    });
+    let generics = with_bounds(
+        &input.generics,
+        attrs.bound_from.as_ref(),
+        &quote! { __v8_derive::TryFromValue },
+    );
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(wrap_in_const(
+        &attrs,
+        &quote! {
+            #[automatically_derived]
+            impl #impl_generics __v8_derive::TryFromValue for #identifier #type_generics #where_clause {
+                fn try_from_value<'__v8>(
+                    input: &'__v8 __v8_derive::v8::Local<'__v8, __v8_derive::v8::Value>,
+                    scope: &'__v8 mut __v8_derive::v8::HandleScope<'_, __v8_derive::v8::Context>,
+                ) -> __v8_derive::errors::Result<Self>
+                where
+                    Self: Sized {
+                        <Self as __v8_derive::TryFromValue>::try_from_value_with(
+                            input,
+                            scope,
+                            __v8_derive::ConversionMode::Lenient,
+                        )
+                }
+
+                fn try_from_value_with<'__v8>(
+                    input: &'__v8 __v8_derive::v8::Local<'__v8, __v8_derive::v8::Value>,
+                    scope: &'__v8 mut __v8_derive::v8::HandleScope<'_, __v8_derive::v8::Context>,
+                    __v8_mode: __v8_derive::ConversionMode,
+                ) -> __v8_derive::errors::Result<Self>
+                where
+                    Self: Sized {
+                        #mode
+                        #body
+                }
+
+                #collect
+            }
+        },
+    ))
+}
+
+/// The body of `try_from_value_with`, or of `try_from_value_collect_with` when `collect` is set
+fn quote_struct(fields: &Fields, attrs: &ContainerAttrs, collect: bool) -> syn::Result<TokenStream> {
+    // positional fields are always read from the input, so there is nothing to take from a default
+    if let (Some(default), Fields::Unnamed(_) | Fields::Unit) = (&attrs.default, fields) {
+        return Err(syn::Error::new(
+            default.span(),
+            "`default` is only supported on structs with named fields",
+        ));
+    }
+
+    if let Some(transparent) = attrs.transparent {
+        if fields.len() != 1 {
+            return Err(syn::Error::new(
+                transparent,
+                "`transparent` requires a struct with exactly one field",
+            ));
+        }
+
+        let field = fields.iter().next().unwrap();
+        let member = member(field, 0);
+        let field_attrs = positional_attrs(field)?;
+        let mode = Mode::quote(field_attrs.mode);
+        let parse = quote_try_from_value(&field.ty, field_attrs.repr.as_ref(), &mode, &quote! { input }, collect)?;
+        return Ok(quote! {
+            #parse.map(|__v8_inner| Self { #member: __v8_inner })
+        });
+    }
+
+    let inits = quote_fields(fields, attrs.rename_all, attrs.default.is_some(), collect)?;
+    let construct = if collect {
+        quote_collect(&quote! { Self }, &inits)
+    } else {
+        let initializers = quote_initializers(&inits);
+        quote! { ::core::result::Result::Ok(Self { #initializers }) }
+    };
+
+    Ok(match fields {
+        Fields::Named(_) => {
+            let default = attrs.default.as_ref().map(|default| {
+                let default = default.quote();
+                quote! { let __v8_default: Self = #default; }
+            });
+            let check = collect.then(|| quote_collect_check(fields));
+
+            quote! {
+                #check
+                #default
+                #construct
+            }
+        }
+        Fields::Unnamed(_) => {
+            let check = collect.then(|| quote_collect_check(fields));
+
+            quote! {
+                #check
+                #construct
+            }
+        }
+        Fields::Unit if collect => quote! {
+            <Self as __v8_derive::TryFromValue>::try_from_value_with(input, scope, __v8_mode)
+                .map_err(|error| ::std::vec![error])
+        },
+        Fields::Unit => quote! {
+            if input.is_null_or_undefined() || input.is_object() {
+                ::core::result::Result::Ok(Self)
+            } else {
+                ::core::result::Result::Err(__v8_derive::errors::Error::ExpectedObject)
+            }
+        },
+    })
+}
+
+/// How a field of the converted struct or variant gets its value
+enum FieldValue {
+    /// An expression of the field type, not read from the input
+    Fixed(TokenStream),
+    /// A conversion of the input, returning a `Result`
+    Converted(TokenStream),
+}
+
+struct FieldInit {
+    member: syn::Member,
+    value: FieldValue,
+}
+
+fn member(field: &syn::Field, index: usize) -> syn::Member {
+    field
+        .ident
+        .clone()
+        .map_or_else(|| syn::Member::Unnamed(index.into()), syn::Member::Named)
+}
+
+/// The attributes of a field that is converted by position, as a newtype, tuple element or transparent field;
+/// it is always read from the input, so skipping it or giving it a default is rejected
+fn positional_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let attrs = FieldAttrs::parse(&field.attrs)?;
+    if let Some(span) = attrs.skip_from {
+        return Err(syn::Error::new(span, "positional fields can not be skipped"));
+    }
+    if let Some(default) = &attrs.default {
+        return Err(syn::Error::new(
+            default.span(),
+            "`default` is only supported on named fields",
+        ));
+    }
+    Ok(attrs)
+}
+
+/// The values of the fields: named fields are read from the object bound to `input`, tuple elements from the
+/// array bound to `input`, and a newtype is converted from `input` itself;
+/// with `container_default` missing fields are taken from the `__v8_default` local
+fn quote_fields(
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    container_default: bool,
+    collect: bool,
+) -> syn::Result<Vec<FieldInit>> {
+    let mut inits = Vec::with_capacity(fields.len());
+
+    for (index, field) in fields.iter().enumerate() {
+        let Some(identifier) = field.ident.as_ref() else {
+            let attrs = positional_attrs(field)?;
+            let mode = Mode::quote(attrs.mode);
+            let member = member(field, index);
+            let value = if fields.len() == 1 {
+                quote_try_from_value(&field.ty, attrs.repr.as_ref(), &mode, &quote! { input }, collect)?
+            } else {
+                let parse = quote_parse_fn(&field.ty, attrs.repr.as_ref(), &mode, collect)?;
+                let index = u32::try_from(index).unwrap_or(u32::MAX);
+                quote! { __v8_derive::helpers::get_element_as(#index, input, scope, #parse) }
+            };
+            inits.push(FieldInit {
+                member,
+                value: FieldValue::Converted(value),
+            });
+            continue;
+        };
+
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let mode = Mode::quote(attrs.mode);
+        let member = member(field, index);
+        let name = attrs.name(identifier, rename_all);
+        let name = if attrs.aliases.is_empty() {
+            quote! { #name }
+        } else {
+            let aliases = &attrs.aliases;
+            quote! { __v8_derive::helpers::find_field_name(&[#name, #(#aliases),*], input, scope) }
+        };
+
+        let default = match &attrs.default {
+            Some(default) => {
+                let default = default.quote();
+                Some(quote! { || #default })
+            }
+            None if container_default => Some(quote! { || __v8_default.#identifier }),
+            None => None,
+        };
+
+        if attrs.skip_from.is_some() {
+            let default = match &attrs.default {
+                Some(default) => default.quote(),
+                None if container_default => quote! { __v8_default.#identifier },
+                None => quote! { ::core::default::Default::default() },
+            };
+            inits.push(FieldInit {
+                member,
+                value: FieldValue::Fixed(default),
+            });
+            continue;
+        }
+
+        let parse = quote_parse_fn(&field.ty, attrs.repr.as_ref(), &mode, collect)?;
+        let value = if let Some(default) = default {
+            quote! { __v8_derive::helpers::get_field_or_else(#name, input, scope, #parse, #default) }
+        } else {
+            quote! { __v8_derive::helpers::get_field_as(#name, input, scope, #parse) }
+        };
+        inits.push(FieldInit {
+            member,
+            value: FieldValue::Converted(value),
+        });
+    }
+
+    Ok(inits)
+}
+
+/// Field initializers returning on the first error
+fn quote_initializers(inits: &[FieldInit]) -> TokenStream {
+    inits
+        .iter()
+        .map(|FieldInit { member, value }| match value {
+            FieldValue::Fixed(value) => quote! { #member: #value, },
+            FieldValue::Converted(value) => quote! { #member: #value?, },
+        })
+        .collect()
+}
+
+/// Returns early when `input` can not hold the fields at all, since every field would report the same error
+fn quote_collect_check(fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote! {
+            if !input.is_object() {
+                return ::core::result::Result::Err(::std::vec![__v8_derive::errors::Error::ExpectedObject]);
+            }
+        },
+        Fields::Unnamed(_) if fields.len() > 1 => quote! {
+            if !input.is_array() {
+                return ::core::result::Result::Err(::std::vec![__v8_derive::errors::Error::ExpectedArray]);
+            }
+        },
+        _ => TokenStream::new(),
+    }
+}
+
+/// Constructs `path` after converting every field, or returns the errors of all failed fields
+fn quote_collect(path: &TokenStream, inits: &[FieldInit]) -> TokenStream {
+    let mut conversions = TokenStream::new();
+    let mut bindings = Vec::new();
+    let mut initializers = TokenStream::new();
+
+    for (index, FieldInit { member, value }) in inits.iter().enumerate() {
+        match value {
+            FieldValue::Fixed(value) => initializers.extend(quote! { #member: #value, }),
+            FieldValue::Converted(value) => {
+                let binding = format_ident!("__v8_{}", index);
+                conversions.extend(quote! {
+                    let #binding = __v8_derive::helpers::collect_errors(&mut __v8_errors, #value);
+                });
+                initializers.extend(quote! { #member: #binding, });
+                bindings.push(binding);
+            }
+        }
+    }
+
+    if bindings.is_empty() {
+        return quote! { ::core::result::Result::Ok(#path { #initializers }) };
+    }
+
+    quote! {
+        let mut __v8_errors = ::std::vec::Vec::new();
+        #conversions
+        match (#(#bindings,)*) {
+            (#(::core::option::Option::Some(#bindings),)*) => ::core::result::Result::Ok(#path { #initializers }),
+            _ => ::core::result::Result::Err(__v8_errors),
+        }
+    }
+}
+
+/// The body of `try_from_value_with`, or of `try_from_value_collect_with` when `collect` is set
+fn quote_enum(
+    identifier: &syn::Ident,
+    data: &DataEnum,
+    attrs: &ContainerAttrs,
+    collect: bool,
+) -> syn::Result<TokenStream> {
+    attrs.ensure_enum()?;
+    let tagging = attrs.tagging()?;
+    let mut variants = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let name = variant_attrs.name(&variant.ident, attrs.rename_all);
+        let aliases = &variant_attrs.aliases;
+        let pattern = quote! { #name #(| #aliases)* };
+        let fields = quote_fields(&variant.fields, variant_attrs.rename_all, false, collect)?;
+        variants.push((variant, name, pattern, fields));
+    }
+    let unknown = quote_error(collect, &quote! { __v8_derive::errors::Error::UnknownVariant(tag) });
+
+    Ok(match &tagging {
+        Tagging::External | Tagging::Adjacent { .. } => {
+            let read_tag = if let Tagging::Adjacent { tag, content } = &tagging {
+                quote_try(
+                    collect,
+                    &quote! { __v8_derive::helpers::get_adjacent_tag(#tag, #content, input, scope) },
+                )
+            } else {
+                quote_try(
+                    collect,
+                    &quote! { __v8_derive::helpers::get_external_tag(input, scope) },
+                )
+            };
+
+            let arms = variants.iter().map(|(variant, name, pattern, fields)| {
+                let construct = if variant.fields.is_empty() {
+                    let ident = &variant.ident;
+                    quote! { ::core::result::Result::Ok(Self::#ident) }
+                } else {
+                    let construct = quote_variant(variant, fields, &quote! { &content }, collect);
+                    let content = quote_try(
+                        collect,
+                        &quote! { __v8_derive::helpers::require_content(content, #name) },
+                    );
+                    quote! {
+                        {
+                            let content = #content;
+                            #construct
+                        }
+                    }
+                };
+
+                quote! { #pattern => #construct, }
+            });
+
+            quote! {
+                let (tag, content) = #read_tag;
+                match tag.as_str() {
+                    #(#arms)*
+                    _ => #unknown,
+                }
+            }
+        }
+        Tagging::Internal { tag } => {
+            let mut arms = TokenStream::new();
+            for (variant, _, pattern, fields) in &variants {
+                if let Fields::Unnamed(unnamed) = &variant.fields {
+                    if unnamed.unnamed.len() > 1 {
+                        return Err(syn::Error::new_spanned(
+                            variant,
+                            "tuple variants are not supported by internally tagged enums",
+                        ));
+                    }
+                }
+
+                let construct = quote_variant(variant, fields, &quote! { input }, collect);
+                arms.extend(quote! { #pattern => #construct, });
+            }
+
+            let read_tag = quote_try(collect, &quote! { __v8_derive::helpers::get_tag(#tag, input, scope) });
+            quote! {
+                let tag = #read_tag;
+                match tag.as_str() {
+                    #arms
+                    _ => #unknown,
+                }
+            }
+        }
+        // the variants are tried one by one, and only the failure to match any of them is reported
+        Tagging::Untagged if collect => quote! {
+            <Self as __v8_derive::TryFromValue>::try_from_value_with(input, scope, __v8_mode)
+                .map_err(|error| ::std::vec![error])
+        },
+        Tagging::Untagged => quote_untagged(
+            identifier,
+            variants
+                .iter()
+                .map(|(variant, _, _, fields)| (*variant, fields.as_slice())),
+        ),
+    })
+}
+
+/// Tries the variants one by one and returns the first that converts
+fn quote_untagged<'a>(
+    identifier: &syn::Ident,
+    variants: impl Iterator<Item = (&'a Variant, &'a [FieldInit])>,
+) -> TokenStream {
+    let attempts = variants.map(|(variant, fields)| {
+        if variant.fields.is_empty() {
+            let ident = &variant.ident;
+            return quote! {
+                if input.is_null_or_undefined() {
+                    return ::core::result::Result::Ok(Self::#ident);
+                }
+            };
+        }
+
+        let construct = quote_variant(variant, fields, &quote! { input }, false);
+        quote! {
+            if let ::core::result::Result::Ok(value) = __v8_derive::helpers::attempt(scope, |scope| #construct) {
+                return ::core::result::Result::Ok(value);
+            }
+        }
+    });
+
+    quote! {
+        #(#attempts)*
+        ::core::result::Result::Err(__v8_derive::errors::Error::NoMatchingVariant(
+            ::std::string::String::from(::core::stringify!(#identifier)),
+        ))
+    }
+}
+
+/// Propagates the error of `expression`, a single error even when collecting; like reading the tag of an enum
+fn quote_try(collect: bool, expression: &TokenStream) -> TokenStream {
+    if collect {
+        quote! { #expression.map_err(|error| ::std::vec![error])? }
+    } else {
+        quote! { #expression? }
+    }
+}
+
+/// Fails with `error`, as a single error when collecting
+fn quote_error(collect: bool, error: &TokenStream) -> TokenStream {
+    if collect {
+        quote! { ::core::result::Result::Err(::std::vec![#error]) }
+    } else {
+        quote! { ::core::result::Result::Err(#error) }
+    }
+}
+
+/// Constructs the variant from its content, which is a `&v8::Local<v8::Value>` expression, out of the values of its
+/// fields from `quote_fields`
+fn quote_variant(variant: &Variant, inits: &[FieldInit], content: &TokenStream, collect: bool) -> TokenStream {
+    let ident = &variant.ident;
+    let path = quote! { Self::#ident };
+    if variant.fields.is_empty() {
+        return quote! { ::core::result::Result::Ok(#path) };
+    }
+
+    let construct = if collect {
+        let check = quote_collect_check(&variant.fields);
+        let construct = quote_collect(&path, inits);
+        quote! {
+            #check
+            #construct
+        }
+    } else {
+        let initializers = quote_initializers(inits);
+        quote! { ::core::result::Result::Ok(#path { #initializers }) }
+    };
+
+    quote! {
+        {
+            let input = #content;
+            #construct
+        }
+    }
+}
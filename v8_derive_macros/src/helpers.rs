@@ -1,108 +1,131 @@
+use crate::attr::{ContainerAttrs, Repr};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 
-pub(crate) fn option_type(ty: &syn::Type) -> Option<&syn::Type> {
-    let syn::Type::Path(ty) = ty else { return None };
-    if ty.qself.is_some() {
-        return None;
-    }
-
-    let ty = &ty.path;
+/// The generics of the derived impl: every type parameter is bounded by `bound`,
+/// unless `#[v8(bound = "...")]` replaced the inferred predicates
+pub(crate) fn with_bounds(
+    generics: &syn::Generics,
+    explicit: Option<&Vec<syn::WherePredicate>>,
+    bound: &TokenStream,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    let predicates = match explicit {
+        Some(predicates) => predicates.clone(),
+        None => generics
+            .type_params()
+            .map(|param| {
+                let ident = &param.ident;
+                syn::parse_quote! { #ident: #bound }
+            })
+            .collect(),
+    };
 
-    if ty.segments.is_empty() || ty.segments.last().unwrap().ident != "Option" {
-        return None;
-    }
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
 
-    if !(ty.segments.len() == 1
-        || (ty.segments.len() == 3
-            && ["core", "std"].contains(&ty.segments[0].ident.to_string().as_str())
-            && ty.segments[1].ident == "option"))
-    {
-        return None;
+/// Places the derived impl in an anonymous const that imports the runtime crate as `__v8_derive`,
+/// `::v8_derive` unless `#[v8(crate = "...")]` says otherwise; all generated paths go through that alias
+pub(crate) fn wrap_in_const(attrs: &ContainerAttrs, implementation: &TokenStream) -> TokenStream {
+    let krate = attrs.krate();
+    quote! {
+        #[doc(hidden)]
+        const _: () = {
+            use #krate as __v8_derive;
+            #implementation
+        };
     }
+}
 
-    let last_segment = ty.segments.last().unwrap();
-    let syn::PathArguments::AngleBracketed(generics) = &last_segment.arguments else {
-        return None;
-    };
-    if generics.args.len() != 1 {
-        return None;
+/// Converts `content` with `<#ty as TryFromValue>::try_from_value_with`, or `try_from_value_collect_with` when
+/// `collect` is set, or with the `try_from_value_with` function of the module given by `#[v8(with = "...")]`;
+/// spanned so unsatisfied bounds point at the field type
+pub(crate) fn quote_try_from_value(
+    ty: &syn::Type,
+    repr: Option<&Repr>,
+    mode: &TokenStream,
+    content: &TokenStream,
+    collect: bool,
+) -> syn::Result<TokenStream> {
+    if let Some(Repr::With(module)) = repr {
+        let parse = quote_spanned! {module.span()=>
+            #module::try_from_value_with(#content, scope, #mode)
+        };
+        return Ok(if collect {
+            quote! { #parse.map_err(|__v8_error| ::std::vec![__v8_error]) }
+        } else {
+            parse
+        });
     }
-    let syn::GenericArgument::Type(inner_type) = &generics.args[0] else {
-        return None;
-    };
 
-    Some(inner_type)
-}
+    ensure_convertible(ty)?;
 
-pub(crate) fn quote_get_field_as(
-    ident: &syn::Ident,
-    identifier: &syn::Ident,
-    field: &syn::Field,
-    optional: bool,
-) -> Option<TokenStream> {
-    let get_operation = if optional {
-        quote! {
-            v8_derive::get_optional_field_as
-        }
+    let method = if collect {
+        quote! { try_from_value_collect_with }
     } else {
-        quote! {
-            v8_derive::get_field_as
-        }
+        quote! { try_from_value_with }
     };
+    Ok(quote_spanned! {ty.span()=>
+        <#ty as __v8_derive::TryFromValue>::#method(#content, scope, #mode)
+    })
+}
 
-    Some(if ident == "String" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_string)?
-        }
-    } else if ident == "bool" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_bool)?
-        }
-    } else if ident == "i8" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_i8)?
-        }
-    } else if ident == "i32" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_i32)?
-        }
-    } else if ident == "i64" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_i64)?
-        }
-    } else if ident == "f64" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_f64)?
-        }
-    } else if ident == "f32" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_f32)?
-        }
-    } else if ident == "u32" {
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, v8_derive::helpers::try_as_u32)?
-        }
-    } else if ident == "Option" {
-        let o_type = option_type(&field.ty)?;
+/// A closure converting its input to `#ty`, for the field helpers of the runtime crate
+pub(crate) fn quote_parse_fn(
+    ty: &syn::Type,
+    repr: Option<&Repr>,
+    mode: &TokenStream,
+    collect: bool,
+) -> syn::Result<TokenStream> {
+    let parse = quote_try_from_value(ty, repr, mode, &quote! { input }, collect)?;
+    Ok(quote! { |input, scope| #parse })
+}
 
-        let syn::Type::Path(type_path) = o_type else {
-            return None;
-        };
+/// Converts `value` with `<#ty as IntoValue>::into_value`, with `IntoInteger` when the field forces an integer
+/// representation, with `IntoObject` for `as_object` or with the `into_value` function of the `with` module;
+/// spanned so unsatisfied bounds point at the field type
+pub(crate) fn quote_into_value(ty: &syn::Type, repr: Option<&Repr>, value: &TokenStream) -> syn::Result<TokenStream> {
+    if !matches!(repr, Some(Repr::With(_))) {
+        ensure_convertible(ty)?;
+    }
 
-        let ident = get_ident(type_path);
-        quote_get_field_as(ident, identifier, field, true)?
-    } else {
-        // Assume the type is a struct and try to convert it
-        quote! {
-            #get_operation(stringify!(#identifier), input, scope, #ident::try_from_value)?
-        }
+    Ok(match repr {
+        Some(Repr::BigInt) => quote_spanned! {ty.span()=>
+            <#ty as __v8_derive::IntoInteger>::into_big_int(#value, scope)
+        },
+        Some(Repr::Number) => quote_spanned! {ty.span()=>
+            <#ty as __v8_derive::IntoInteger>::into_number(#value, scope)
+        },
+        Some(Repr::Object) => quote_spanned! {ty.span()=>
+            <#ty as __v8_derive::into::IntoObject>::into_object(#value, scope)
+        },
+        Some(Repr::With(module)) => quote_spanned! {module.span()=>
+            #module::into_value(#value, scope)
+        },
+        Some(Repr::Map) | None => quote_spanned! {ty.span()=>
+            <#ty as __v8_derive::IntoValue>::into_value(#value, scope)
+        },
     })
 }
 
-pub(crate) fn get_ident(type_path: &syn::TypePath) -> &syn::Ident {
-    let path = &type_path.path;
-    // todo: fix unwrap
-    let segment = path.segments.first().unwrap();
-    (&segment.ident) as _
+/// Rejects the field types no conversion can be implemented for, as the orphan rule leaves that to this crate;
+/// reporting them here points at the type instead of at an unsatisfied bound listing every implementation
+fn ensure_convertible(ty: &syn::Type) -> syn::Result<()> {
+    match ty {
+        syn::Type::Ptr(pointer) => Err(syn::Error::new_spanned(
+            pointer,
+            "raw pointers can not be converted; use `with` to convert them",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The error reported for unions, which can not be derived
+pub(crate) fn unsupported_union(union: &syn::DataUnion) -> syn::Error {
+    syn::Error::new(
+        union.union_token.span,
+        "only structs and enums are supported, not unions",
+    )
 }
@@ -0,0 +1,310 @@
+//! Expansion of the `IntoValue` derive.
+
+use crate::{
+    attr::{ContainerAttrs, FieldAttrs, Tagging, VariantAttrs},
+    case::RenameRule,
+    helpers::{quote_into_value, unsupported_union, with_bounds, wrap_in_const},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, spanned::Spanned, Data, DataEnum, DeriveInput, Fields, LitStr};
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let struct_identifier = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(syn::DataStruct { fields, .. }) => {
+            attrs.ensure_struct()?;
+            quote_struct(fields, &attrs)?
+        }
+        Data::Enum(data) => quote_enum(data, &attrs)?,
+        Data::Union(data) => return Err(unsupported_union(data)),
+    };
+
+    let generics = with_bounds(
+        &input.generics,
+        attrs.bound_into.as_ref(),
+        &quote! { __v8_derive::IntoValue },
+    );
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(wrap_in_const(
+        &attrs,
+        &quote! {
+            #[automatically_derived]
+            impl #impl_generics __v8_derive::IntoValue for #struct_identifier #type_generics #where_clause {
+                fn into_value<'__v8>(
+                    self,
+                    scope: &mut __v8_derive::v8::HandleScope<'__v8>,
+                ) -> __v8_derive::v8::Local<'__v8, __v8_derive::v8::Value> {
+                    #body
+                }
+            }
+        },
+    ))
+}
+
+fn quote_struct(fields: &Fields, attrs: &ContainerAttrs) -> syn::Result<TokenStream> {
+    let member = |field: &syn::Field, index: usize| {
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| syn::Member::Unnamed(index.into()), syn::Member::Named);
+        quote! { self.#member }
+    };
+
+    if let Some(transparent) = attrs.transparent {
+        if fields.len() != 1 {
+            return Err(syn::Error::new(
+                transparent,
+                "`transparent` requires a struct with exactly one field",
+            ));
+        }
+
+        let field = fields.iter().next().unwrap();
+        let repr = positional_attrs(field)?.repr;
+        return quote_into_value(&field.ty, repr.as_ref(), &member(field, 0));
+    }
+
+    quote_content(fields, attrs.rename_all, member)
+}
+
+/// Sets every named field on the v8 Object bound to `object`
+fn quote_set_fields(
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    value: impl Fn(&syn::Field, usize) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    let mut implementation = TokenStream::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let Some(identifier) = field.ident.as_ref() else {
+            continue;
+        };
+
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip_into.is_some() {
+            continue;
+        }
+
+        let name = attrs.name(identifier, rename_all);
+
+        let value = value(field, index);
+        let convert = quote_into_value(&field.ty, attrs.repr.as_ref(), &value)?;
+        let set = quote! {
+            let js_key = __v8_derive::v8::String::new(scope, #name).unwrap().into();
+            let js_val = #convert;
+            object.set(scope, js_key, js_val);
+        };
+
+        implementation.extend(match &attrs.skip_into_if {
+            Some(skip_if) => quote! {
+                if !#skip_if(&#value) {
+                    #set
+                }
+            },
+            None => set,
+        });
+    }
+
+    Ok(implementation)
+}
+
+/// The attributes of a field that is converted by position, as a newtype, tuple element or transparent field;
+/// leaving it out would shift every following element, so skipping it is rejected
+fn positional_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let attrs = FieldAttrs::parse(&field.attrs)?;
+    let skip = attrs
+        .skip_into
+        .or_else(|| attrs.skip_into_if.as_ref().map(Spanned::span));
+    match skip {
+        Some(span) => Err(syn::Error::new(span, "positional fields can not be skipped")),
+        None => Ok(attrs),
+    }
+}
+
+/// Sets a string property on the v8 Object bound to `object`
+fn quote_set(key: &LitStr, value: &TokenStream) -> TokenStream {
+    quote! {
+        let js_key = __v8_derive::v8::String::new(scope, #key).unwrap().into();
+        let js_val = #value;
+        object.set(scope, js_key, js_val);
+    }
+}
+
+fn quote_str(value: &LitStr) -> TokenStream {
+    quote! {
+        __v8_derive::v8::String::new(scope, #value).unwrap().into()
+    }
+}
+
+/// Name of the local a variant field gets bound to in the match pattern
+fn binding(field: &syn::Field, index: usize) -> syn::Ident {
+    match &field.ident {
+        Some(ident) => format_ident!("__v8_{}", ident.unraw()),
+        None => format_ident!("__v8_{}", index),
+    }
+}
+
+fn quote_binding(field: &syn::Field, index: usize) -> TokenStream {
+    let binding = binding(field, index);
+    quote! { #binding }
+}
+
+fn quote_enum(data: &DataEnum, attrs: &ContainerAttrs) -> syn::Result<TokenStream> {
+    attrs.ensure_enum()?;
+    let tagging = attrs.tagging()?;
+    let mut arms = TokenStream::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let name = variant_attrs.name(ident, attrs.rename_all);
+        let rename_all = variant_attrs.rename_all;
+        let bindings: Vec<_> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| binding(field, index))
+            .collect();
+
+        let pattern = match &variant.fields {
+            Fields::Unit => quote! { Self::#ident },
+            Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings),*) },
+            Fields::Named(fields) => {
+                let identifiers = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#ident { #(#identifiers: #bindings),* } }
+            }
+        };
+
+        let body = match &tagging {
+            Tagging::External if variant.fields.is_empty() => quote_str(&name),
+            Tagging::External => {
+                let content = quote_content(&variant.fields, rename_all, quote_binding)?;
+                let set_content = quote_set(&name, &content);
+                quote! {
+                    let object = __v8_derive::v8::Object::new(scope);
+                    #set_content
+                    object.into()
+                }
+            }
+            Tagging::Internal { tag } => {
+                let tag = LitStr::new(tag, ident.span());
+                let set_tag = quote_set(&tag, &quote_str(&name));
+
+                match &variant.fields {
+                    Fields::Unnamed(fields) if fields.unnamed.len() > 1 => {
+                        return Err(syn::Error::new_spanned(
+                            variant,
+                            "tuple variants are not supported by internally tagged enums",
+                        ));
+                    }
+                    Fields::Unnamed(fields) => {
+                        // newtype variant; the tag is added to the inner object
+                        let field = &fields.unnamed[0];
+                        let repr = positional_attrs(field)?.repr;
+                        let inner = quote_into_value(&field.ty, repr.as_ref(), &quote_binding(field, 0))?;
+                        quote! {
+                            let value = #inner;
+                            __v8_derive::helpers::set_tag(#tag, #name, value, scope)
+                        }
+                    }
+                    _ => {
+                        let set_fields = quote_set_fields(&variant.fields, rename_all, quote_binding)?;
+                        quote! {
+                            let object = __v8_derive::v8::Object::new(scope);
+                            #set_tag
+                            #set_fields
+                            object.into()
+                        }
+                    }
+                }
+            }
+            Tagging::Adjacent { tag, content } => {
+                let tag = LitStr::new(tag, ident.span());
+                let set_tag = quote_set(&tag, &quote_str(&name));
+                let set_content = if variant.fields.is_empty() {
+                    TokenStream::new()
+                } else {
+                    quote_set(
+                        &LitStr::new(content, ident.span()),
+                        &quote_content(&variant.fields, rename_all, quote_binding)?,
+                    )
+                };
+
+                quote! {
+                    let object = __v8_derive::v8::Object::new(scope);
+                    #set_tag
+                    #set_content
+                    object.into()
+                }
+            }
+            Tagging::Untagged if variant.fields.is_empty() => quote! {
+                __v8_derive::v8::null(scope).into()
+            },
+            Tagging::Untagged => quote_content(&variant.fields, rename_all, quote_binding)?,
+        };
+
+        arms.extend(quote! {
+            #pattern => {
+                #body
+            }
+        });
+    }
+
+    Ok(quote! {
+        match self {
+            #arms
+        }
+    })
+}
+
+/// Converts the fields of a struct or variant into a single v8 Value; newtypes convert as their inner value,
+/// tuples become an Array, named fields an Object and unit types `null`
+fn quote_content(
+    fields: &Fields,
+    rename_all: Option<RenameRule>,
+    value: impl Fn(&syn::Field, usize) -> TokenStream,
+) -> syn::Result<TokenStream> {
+    Ok(match fields {
+        Fields::Unit => quote! { __v8_derive::v8::null(scope).into() },
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            let field = &unnamed.unnamed[0];
+            let repr = positional_attrs(field)?.repr;
+            quote_into_value(&field.ty, repr.as_ref(), &value(field, 0))?
+        }
+        Fields::Unnamed(unnamed) => {
+            let length = i32::try_from(unnamed.unnamed.len()).unwrap_or(i32::MAX);
+            let mut elements = TokenStream::new();
+            for (index, field) in unnamed.unnamed.iter().enumerate() {
+                let repr = positional_attrs(field)?.repr;
+                let inner = quote_into_value(&field.ty, repr.as_ref(), &value(field, index))?;
+                let index = u32::try_from(index).unwrap_or(u32::MAX);
+                elements.extend(quote! {
+                    let js_val = #inner;
+                    array.set_index(scope, #index, js_val);
+                });
+            }
+
+            quote! {
+                {
+                    let array = __v8_derive::v8::Array::new(scope, #length);
+                    #elements
+                    array.into()
+                }
+            }
+        }
+        Fields::Named(_) => {
+            let set_fields = quote_set_fields(fields, rename_all, value)?;
+
+            quote! {
+                {
+                    let object = __v8_derive::v8::Object::new(scope);
+                    #set_fields
+                    object.into()
+                }
+            }
+        }
+    })
+}